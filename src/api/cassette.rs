@@ -0,0 +1,252 @@
+//! 요청/응답 녹화 및 재생(`cassette` feature)
+//! - Cassette::record: 실제 transport로 보낸 요청/응답을 파일(JSON)에 녹화
+//! - Cassette::replay: 녹화한 파일의 응답을 순서대로 돌려줌(네트워크 불필요)
+//! - 토큰, CI, 계좌번호 등은 녹화 전에 가려지므로 녹화 파일을 저장소에 커밋해도 됨
+use super::{
+    pipeline::BoxFuture,
+    redact::{is_secret_header, redact_form, redact_json, REDACTED},
+    transport::{HttpRequest, HttpResponse, Transport},
+};
+use crate::ApiError;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll},
+};
+use tower::{BoxError, Service};
+
+/// 녹화하지 않는 헤더(본문을 가리면 길이가 달라지고, 날짜는 녹화할 때마다 달라짐)
+const SKIPPED_HEADERS: [&str; 2] = ["content-length", "date"];
+/// 녹화된 요청
+/// - method: HTTP method
+/// - url: URL(query parameter 포함)
+/// - headers: 요청 헤더
+/// - body: 요청 본문
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+/// 녹화된 응답
+/// - status: HTTP 상태코드
+/// - headers: 응답 헤더
+/// - body: 응답 본문
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+/// 녹화된 요청/응답 한 쌍
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+enum Mode {
+    Record(Transport),
+    Replay { next: usize },
+}
+
+struct State {
+    mode: Mode,
+    interactions: Vec<Interaction>,
+}
+
+/// 녹화/재생 transport(복제한 Cassette는 같은 녹화 내용을 공유)
+#[derive(Clone)]
+pub struct Cassette {
+    path: Arc<PathBuf>,
+    state: Arc<Mutex<State>>,
+}
+
+impl Cassette {
+    /// inner transport로 요청을 보내고 요청/응답을 path에 녹화(기존 파일은 덮어씀)
+    pub fn record<P: AsRef<Path>>(path: P, inner: Transport) -> Self {
+        Self {
+            path: Arc::new(path.as_ref().to_path_buf()),
+            state: Arc::new(Mutex::new(State {
+                mode: Mode::Record(inner),
+                interactions: Vec::new(),
+            })),
+        }
+    }
+
+    /// path에 녹화된 응답을 녹화된 순서대로 재생
+    /// - 요청의 method와 URL 경로가 녹화된 요청과 다르면 ApiError::Cassette 반환
+    pub fn replay<P: AsRef<Path>>(path: P) -> Result<Self, ApiError> {
+        let path = path.as_ref().to_path_buf();
+        let interactions = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()))
+            .map_err(|reason| ApiError::Cassette {
+                path: path.clone(),
+                reason,
+            })?;
+        Ok(Self {
+            path: Arc::new(path),
+            state: Arc::new(Mutex::new(State {
+                mode: Mode::Replay { next: 0 },
+                interactions,
+            })),
+        })
+    }
+
+    /// OAuthApi에 연결할 transport
+    pub fn transport(&self) -> Transport {
+        Transport::new(self.clone())
+    }
+
+    /// 지금까지 녹화(재생 모드에서는 파일에서 읽은) 요청/응답
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().interactions.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn error(&self, reason: String) -> ApiError {
+        ApiError::Cassette {
+            path: self.path.to_path_buf(),
+            reason,
+        }
+    }
+
+    fn replay_next(&self, request: &RecordedRequest) -> Result<HttpResponse, ApiError> {
+        let mut state = self.lock();
+        let State {
+            mode: Mode::Replay { next },
+            interactions,
+        } = &mut *state
+        else {
+            unreachable!("replay_next is only called in replay mode");
+        };
+        let Some(interaction) = interactions.get(*next) else {
+            return Err(self.error(format!(
+                "no recorded interaction left for {} {}",
+                request.method, request.url
+            )));
+        };
+        let recorded = &interaction.request;
+        if recorded.method != request.method || path(&recorded.url) != path(&request.url) {
+            return Err(self.error(format!(
+                "interaction #{} was recorded for {} {}, but got {} {}",
+                *next, recorded.method, recorded.url, request.method, request.url
+            )));
+        }
+        *next += 1;
+        into_response(&interaction.response).map_err(|reason| self.error(reason))
+    }
+
+    fn save(&self, interaction: Interaction) -> Result<(), ApiError> {
+        let mut state = self.lock();
+        state.interactions.push(interaction);
+        let json = serde_json::to_string_pretty(&state.interactions)?;
+        std::fs::write(self.path.as_ref(), json).map_err(|e| self.error(e.to_string()))
+    }
+}
+
+impl Service<HttpRequest> for Cassette {
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<HttpResponse, BoxError>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let cassette = self.clone();
+        Box::pin(async move {
+            let recorded_request = record_request(&request);
+            let inner = match &cassette.lock().mode {
+                Mode::Record(inner) => Some(inner.clone()),
+                Mode::Replay { .. } => None,
+            };
+            let Some(inner) = inner else {
+                return Ok(cassette.replay_next(&recorded_request)?);
+            };
+            let response = inner.send(request).await?;
+            cassette.save(Interaction {
+                request: recorded_request,
+                response: record_response(&response),
+            })?;
+            Ok(response)
+        })
+    }
+}
+
+fn path(url: &str) -> &str {
+    url.split('?').next().unwrap_or_default()
+}
+
+fn record_request(request: &HttpRequest) -> RecordedRequest {
+    let url = request.uri().to_string();
+    let url = match url.split_once('?') {
+        Some((path, query)) => format!("{}?{}", path, redact_form(query)),
+        None => url,
+    };
+    let is_form = request
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"));
+    RecordedRequest {
+        method: request.method().to_string(),
+        url,
+        headers: record_headers(request.headers()),
+        body: if is_form {
+            redact_form(request.body())
+        } else {
+            redact_json(request.body())
+        },
+    }
+}
+
+fn record_response(response: &HttpResponse) -> RecordedResponse {
+    RecordedResponse {
+        status: response.status().as_u16(),
+        headers: record_headers(response.headers()),
+        body: redact_json(response.body()),
+    }
+}
+
+fn into_response(recorded: &RecordedResponse) -> Result<HttpResponse, String> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &recorded.headers {
+        headers.insert(
+            HeaderName::from_bytes(name.as_bytes()).map_err(|e| e.to_string())?,
+            HeaderValue::from_str(value).map_err(|e| e.to_string())?,
+        );
+    }
+    let mut response = http::Response::builder()
+        .status(recorded.status)
+        .body(recorded.body.clone())
+        .map_err(|e| e.to_string())?;
+    *response.headers_mut() = headers;
+    Ok(response)
+}
+
+fn record_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+        .map(|(name, value)| {
+            let value = if is_secret_header(name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
@@ -0,0 +1,100 @@
+use super::oauth::OAuthApi;
+use crate::types::{
+    endpoint::{Endpoint, InquiryEndpoint},
+    inquiry::{balance, real_name, receive, remit_list, transaction_list},
+    AuthorizationHeader,
+};
+use crate::ApiError;
+
+impl OAuthApi {
+    /// 잔액조회
+    /// - access_token: "inquiry" scope을 포함한 사용자 토큰
+    pub async fn balance(
+        &self,
+        access_token: String,
+        query: &balance::RequestBody,
+    ) -> Result<balance::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::Inquiry(InquiryEndpoint::Balance),
+            balance::METHOD,
+            Some(AuthorizationHeader::new(access_token)),
+            Some(query),
+        )
+        .await
+    }
+
+    /// 거래내역조회
+    /// - access_token: "inquiry" scope을 포함한 사용자 토큰
+    pub async fn transaction_list(
+        &self,
+        access_token: String,
+        query: &transaction_list::RequestBody,
+    ) -> Result<transaction_list::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::Inquiry(InquiryEndpoint::ListTransaction),
+            transaction_list::METHOD,
+            Some(AuthorizationHeader::new(access_token)),
+            Some(query),
+        )
+        .await
+    }
+
+    /// 계좌실명조회
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub async fn real_name(
+        &self,
+        access_token: String,
+        body: &real_name::RequestBody,
+    ) -> Result<real_name::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::Inquiry(InquiryEndpoint::RealName),
+            real_name::METHOD,
+            Some(AuthorizationHeader::new(access_token)),
+            Some(body),
+        )
+        .await
+    }
+
+    /// 수취조회
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub async fn receive(
+        &self,
+        access_token: String,
+        body: &receive::RequestBody,
+    ) -> Result<receive::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::Inquiry(InquiryEndpoint::Receive),
+            receive::METHOD,
+            Some(AuthorizationHeader::new(access_token)),
+            Some(body),
+        )
+        .await
+    }
+
+    /// 수취조회 후 입금이체에 사용할 수취인 정보를 반환(수취조회가 성공하지 않은 경우 None)
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub async fn verify_recipient(
+        &self,
+        access_token: String,
+        body: receive::RequestBody,
+    ) -> Result<Option<receive::VerifiedRecipient>, ApiError> {
+        let response = self.receive(access_token, &body).await?;
+        Ok(receive::VerifiedRecipient::from_inquiry(&body, &response))
+    }
+
+    /// 송금인정보조회
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub async fn remit_list(
+        &self,
+        access_token: String,
+        body: &remit_list::RequestBody,
+    ) -> Result<remit_list::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::Inquiry(InquiryEndpoint::RemitList),
+            remit_list::METHOD,
+            Some(AuthorizationHeader::new(access_token)),
+            Some(body),
+        )
+        .await
+    }
+}
@@ -0,0 +1,387 @@
+//! API 호출 지표(`metrics` feature)
+//! - OAuthApiBuilder::metrics로 설정하면 호출 결과(재시도 후 최종 결과), 재시도별 결과, 호출량 제한 대기/거절을 집계하고 render로 Prometheus text 형식으로 출력
+//! - middleware로만 추가하면 호출 결과만 집계(재시도별 결과와 호출량 제한 지표는 남지 않음)
+//! - label의 endpoint/group은 Endpoint::as_str/EndpointGroup::as_str(예: "inquiry.balance", "inquiry")
+//! - open_banking_calls_total{endpoint, rsp_code}: 호출 수(응답코드가 없으면 rsp_code="")
+//! - open_banking_errors_total{endpoint, code}: 실패 수(응답코드, "HTTP_<상태코드>", "RATE_LIMITED" 등)
+//! - open_banking_call_duration_seconds{endpoint}: 소요시간 histogram
+//! - open_banking_attempts_total{endpoint, code}: 재시도를 포함한 호출 시도 수(성공은 code="OK")
+//! - open_banking_throttled_total{group, outcome}: 호출량 제한에 걸린 요청 수(outcome="queued": 대기 후 호출, "rejected": 거절)
+//! - open_banking_throttle_wait_seconds{group}: 호출량 제한으로 대기한 시간 histogram
+//! - open_banking_tokens_issued_total{grant_type}: 토큰발급 수
+//! - open_banking_transfer_amount_won{endpoint}: 이체 성공 금액 histogram
+use super::pipeline::{ApiRequest, BoxFuture, Middleware, Next, RawResponse};
+use crate::types::endpoint::{Endpoint, EndpointGroup, OAuthEndpoint, TransferEndpoint};
+use crate::ApiError;
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// 소요시간 histogram 구간(초)
+const DURATION_BUCKETS: [f64; 9] = [0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+/// 이체 금액 histogram 구간(원)
+const AMOUNT_BUCKETS: [f64; 6] = [
+    10_000.0,
+    100_000.0,
+    1_000_000.0,
+    10_000_000.0,
+    100_000_000.0,
+    1_000_000_000.0,
+];
+
+struct Histogram {
+    bounds: &'static [f64],
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            counts: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        for (bound, count) in self.bounds.iter().zip(self.counts.iter_mut()) {
+            if value <= *bound {
+                *count += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    calls: BTreeMap<(String, String), u64>,
+    errors: BTreeMap<(String, String), u64>,
+    durations: BTreeMap<String, Histogram>,
+    attempts: BTreeMap<(String, String), u64>,
+    throttled: BTreeMap<(String, String), u64>,
+    throttle_waits: BTreeMap<String, Histogram>,
+    tokens_issued: BTreeMap<String, u64>,
+    transfer_amounts: BTreeMap<String, Histogram>,
+}
+
+/// API 호출 지표 middleware(여러 OAuthApi가 같은 Metrics를 공유할 수 있음)
+#[derive(Default)]
+pub struct Metrics {
+    registry: Mutex<Registry>,
+}
+
+impl Metrics {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Prometheus text exposition format으로 출력
+    pub fn render(&self) -> String {
+        let registry = self.lock();
+        let mut out = String::new();
+        write_header(&mut out, "open_banking_calls_total", "counter", "API calls");
+        for ((endpoint, rsp_code), value) in &registry.calls {
+            let labels = [
+                ("endpoint", endpoint.as_str()),
+                ("rsp_code", rsp_code.as_str()),
+            ];
+            write_sample(&mut out, "open_banking_calls_total", &labels, *value as f64);
+        }
+        write_header(
+            &mut out,
+            "open_banking_errors_total",
+            "counter",
+            "Failed API calls",
+        );
+        for ((endpoint, code), value) in &registry.errors {
+            let labels = [("endpoint", endpoint.as_str()), ("code", code.as_str())];
+            write_sample(
+                &mut out,
+                "open_banking_errors_total",
+                &labels,
+                *value as f64,
+            );
+        }
+        write_histograms(
+            &mut out,
+            "open_banking_call_duration_seconds",
+            "API call duration in seconds",
+            "endpoint",
+            &registry.durations,
+        );
+        write_header(
+            &mut out,
+            "open_banking_attempts_total",
+            "counter",
+            "API call attempts including retries",
+        );
+        for ((endpoint, code), value) in &registry.attempts {
+            let labels = [("endpoint", endpoint.as_str()), ("code", code.as_str())];
+            write_sample(
+                &mut out,
+                "open_banking_attempts_total",
+                &labels,
+                *value as f64,
+            );
+        }
+        write_header(
+            &mut out,
+            "open_banking_throttled_total",
+            "counter",
+            "Requests queued or rejected by the client-side rate limiter",
+        );
+        for ((group, outcome), value) in &registry.throttled {
+            let labels = [("group", group.as_str()), ("outcome", outcome.as_str())];
+            write_sample(
+                &mut out,
+                "open_banking_throttled_total",
+                &labels,
+                *value as f64,
+            );
+        }
+        write_histograms(
+            &mut out,
+            "open_banking_throttle_wait_seconds",
+            "Time spent waiting for the client-side rate limiter in seconds",
+            "group",
+            &registry.throttle_waits,
+        );
+        write_header(
+            &mut out,
+            "open_banking_tokens_issued_total",
+            "counter",
+            "Access tokens issued",
+        );
+        for (grant_type, value) in &registry.tokens_issued {
+            let labels = [("grant_type", grant_type.as_str())];
+            write_sample(
+                &mut out,
+                "open_banking_tokens_issued_total",
+                &labels,
+                *value as f64,
+            );
+        }
+        write_histograms(
+            &mut out,
+            "open_banking_transfer_amount_won",
+            "Successful transfer amounts in KRW",
+            "endpoint",
+            &registry.transfer_amounts,
+        );
+        out
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Registry> {
+        self.registry.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 재시도 loop의 호출 시도 결과를 기록
+    pub(crate) fn record_attempt(
+        &self,
+        endpoint: &Endpoint,
+        result: &Result<RawResponse, ApiError>,
+    ) {
+        let code = match result {
+            Ok(_) => "OK".to_string(),
+            Err(e) => error_code(e),
+        };
+        *self
+            .lock()
+            .attempts
+            .entry((endpoint.as_str().to_string(), code))
+            .or_default() += 1;
+    }
+
+    /// 호출량 제한의 대기/거절을 기록(바로 호출된 요청은 기록하지 않음)
+    pub(crate) fn record_throttle(
+        &self,
+        group: EndpointGroup,
+        waited: &Result<Duration, ApiError>,
+    ) {
+        let outcome = match waited {
+            Ok(wait) if wait.is_zero() => return,
+            Ok(_) => "queued",
+            Err(_) => "rejected",
+        };
+        let group = group.as_str().to_string();
+        let mut registry = self.lock();
+        *registry
+            .throttled
+            .entry((group.clone(), outcome.to_string()))
+            .or_default() += 1;
+        if let Ok(wait) = waited {
+            registry
+                .throttle_waits
+                .entry(group)
+                .or_insert_with(|| Histogram::new(&DURATION_BUCKETS))
+                .observe(wait.as_secs_f64());
+        }
+    }
+
+    fn record(
+        &self,
+        request: &ApiRequest,
+        result: &Result<RawResponse, ApiError>,
+        elapsed: Duration,
+    ) {
+        let endpoint = request.endpoint.as_str().to_string();
+        let mut registry = self.lock();
+        registry
+            .durations
+            .entry(endpoint.clone())
+            .or_insert_with(|| Histogram::new(&DURATION_BUCKETS))
+            .observe(elapsed.as_secs_f64());
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                let rsp_code = match e {
+                    ApiError::OpenBanking { rsp_code, .. } => rsp_code.code().to_string(),
+                    _ => String::new(),
+                };
+                *registry
+                    .calls
+                    .entry((endpoint.clone(), rsp_code))
+                    .or_default() += 1;
+                *registry
+                    .errors
+                    .entry((endpoint, error_code(e)))
+                    .or_default() += 1;
+                return;
+            }
+        };
+        let body: Value = serde_json::from_str(&response.body).unwrap_or_default();
+        let rsp_code = body["rsp_code"].as_str().unwrap_or_default().to_string();
+        *registry
+            .calls
+            .entry((endpoint.clone(), rsp_code))
+            .or_default() += 1;
+        match request.endpoint {
+            Endpoint::OAuth(OAuthEndpoint::Token) => {
+                let grant_type = request
+                    .body
+                    .as_deref()
+                    .and_then(|body| serde_urlencoded::from_str::<Vec<(String, String)>>(body).ok())
+                    .and_then(|pairs| {
+                        pairs
+                            .into_iter()
+                            .find(|(field, _)| field == "grant_type")
+                            .map(|(_, value)| value)
+                    })
+                    .unwrap_or_default();
+                *registry.tokens_issued.entry(grant_type).or_default() += 1;
+            }
+            Endpoint::Transfer(
+                TransferEndpoint::WithdrawFinNum
+                | TransferEndpoint::WithdrawAcntNum
+                | TransferEndpoint::DepositFinNum
+                | TransferEndpoint::DepositAcntNum,
+            ) => {
+                let amounts = registry
+                    .transfer_amounts
+                    .entry(endpoint)
+                    .or_insert_with(|| Histogram::new(&AMOUNT_BUCKETS));
+                for amount in transfer_amounts(&body) {
+                    amounts.observe(amount);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Middleware for Metrics {
+    fn handle<'a>(
+        &'a self,
+        request: ApiRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<RawResponse, ApiError>> {
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = next.run(request.clone()).await;
+            self.record(&request, &result, started.elapsed());
+            result
+        })
+    }
+}
+
+fn error_code(error: &ApiError) -> String {
+    match error {
+        ApiError::OpenBanking { rsp_code, .. } => rsp_code.code().to_string(),
+        ApiError::Http { status, .. } => format!("HTTP_{}", status.as_u16()),
+        ApiError::Decode { .. } => "DECODE".to_string(),
+        ApiError::RateLimited { .. } => "RATE_LIMITED".to_string(),
+        ApiError::ReqwestError(_) | ApiError::Transport(_) => "TRANSPORT".to_string(),
+        _ => "CLIENT".to_string(),
+    }
+}
+
+/// 이체 성공 금액(출금이체는 tran_amt, 입금이체는 참가기관 응답이 성공인 res_list의 tran_amt)
+fn transfer_amounts(body: &Value) -> Vec<f64> {
+    if let Some(tran_amt) = body["tran_amt"].as_str() {
+        return tran_amt.parse().into_iter().collect();
+    }
+    body["res_list"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|item| matches!(item["bank_rsp_code"].as_str(), None | Some("000")))
+        .filter_map(|item| item["tran_amt"].as_str()?.parse().ok())
+        .collect()
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: f64) {
+    let labels: Vec<String> = labels
+        .iter()
+        .map(|(label, value)| format!("{}=\"{}\"", label, escape(value)))
+        .collect();
+    let _ = writeln!(out, "{}{{{}}} {}", name, labels.join(","), value);
+}
+
+fn write_histograms(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    label: &str,
+    histograms: &BTreeMap<String, Histogram>,
+) {
+    write_header(out, name, "histogram", help);
+    let bucket = format!("{}_bucket", name);
+    for (key, histogram) in histograms {
+        for (bound, count) in histogram.bounds.iter().zip(&histogram.counts) {
+            let le = bound.to_string();
+            let labels = [(label, key.as_str()), ("le", le.as_str())];
+            write_sample(out, &bucket, &labels, *count as f64);
+        }
+        let labels = [(label, key.as_str()), ("le", "+Inf")];
+        write_sample(out, &bucket, &labels, histogram.count as f64);
+        let labels = [(label, key.as_str())];
+        write_sample(out, &format!("{}_sum", name), &labels, histogram.sum);
+        write_sample(
+            out,
+            &format!("{}_count", name),
+            &labels,
+            histogram.count as f64,
+        );
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
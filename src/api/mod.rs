@@ -1 +1,14 @@
+#[cfg(feature = "cassette")]
+pub mod cassette;
+pub mod inquiry;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod oauth;
+pub mod pipeline;
+pub mod rate_limit;
+pub(crate) mod redact;
+pub mod retry;
+pub mod trace;
+pub mod transfer;
+pub mod transport;
+pub mod user;
@@ -1,18 +1,118 @@
-use crate::types::{endpoint::*, GetHash, HttpMethod};
+#[cfg(feature = "metrics")]
+use super::metrics::Metrics;
+use super::{
+    pipeline::{ApiRequest, Middleware, Next, RawResponse},
+    rate_limit::RateLimiter,
+    retry::RetryPolicy,
+    trace,
+    transport::Transport,
+};
+use crate::config::ClientConfig;
+use crate::types::{
+    endpoint::*,
+    oauth::{revoke, token},
+    AuthorizationHeader, GetHash, HttpMethod,
+};
 use crate::ApiError;
 use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tracing::Instrument;
+
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
 pub struct OAuthApi {
-    client: reqwest::Client,
+    config: Option<ClientConfig>,
+    environment: Environment,
+    transport: Transport,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Arc<RateLimiter>,
+    #[cfg(feature = "metrics")]
+    metrics: Option<Arc<Metrics>>,
+}
+
+impl Default for OAuthApi {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl OAuthApi {
     pub fn new() -> Self {
         Self {
-            client: reqwest::Client::new(),
+            config: None,
+            environment: Environment::default(),
+            transport: Transport::default(),
+            middlewares: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: Arc::new(RateLimiter::new()),
+            #[cfg(feature = "metrics")]
+            metrics: None,
         }
     }
 
+    pub fn builder() -> OAuthApiBuilder {
+        OAuthApiBuilder::default()
+    }
+
+    /// 이용기관 설정(API 환경도 설정의 환경으로 바뀜)
+    pub fn with_config(mut self, config: ClientConfig) -> Self {
+        self.environment = config.environment();
+        self.config = Some(config);
+        self
+    }
+
+    /// API 환경 설정
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// 재시도 정책 설정(bank_tran_id를 담는 조회서비스/출금이체/입금이체는 정책과 무관하게 재시도하지 않음)
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// 호출량 제한 설정(여러 client가 같은 제한을 공유하려면 같은 RateLimiter를 넘김)
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// HTTP transport 설정(tower layer를 씌운 service나 테스트용 fake service 사용 시)
+    pub fn with_transport(mut self, transport: Transport) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// 사용자 middleware 추가(먼저 추가한 middleware가 바깥쪽에서 실행됨)
+    pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    pub fn config(&self) -> Option<&ClientConfig> {
+        self.config.as_ref()
+    }
+
+    pub fn environment(&self) -> Environment {
+        self.environment
+    }
+
+    fn require_config(&self) -> Result<&ClientConfig, ApiError> {
+        self.config
+            .as_ref()
+            .ok_or_else(|| ApiError::Config("client config is not set".to_string()))
+    }
+
     pub async fn call<ReqHeader, ReqBody, ResBody>(
         &self,
         endpoint: Endpoint,
@@ -21,91 +121,230 @@ impl OAuthApi {
         body: Option<ReqBody>,
     ) -> Result<ResBody, ApiError>
     where
-        ReqHeader: Serialize + GetHash,
+        ReqHeader: GetHash,
         ReqBody: Serialize,
         ResBody: DeserializeOwned,
     {
-        let result = match method {
-            HttpMethod::Get => match header {
-                None => {
-                    self.client
-                        .get(Into::<String>::into(endpoint))
-                        .send()
-                        .await?
-                        .text()
-                        .await?
+        let mut request = ApiRequest::new(endpoint, method, header.as_ref(), body.as_ref())?;
+        request.url = endpoint.url(self.environment);
+        self.execute(request).await?.decode()
+    }
+
+    /// 토큰발급(사용자 토큰: authorization_code, 이용기관 토큰: client_credentials)
+    pub async fn token(&self, body: &token::RequestBody) -> Result<token::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::OAuth(OAuthEndpoint::Token),
+            token::METHOD,
+            None::<AuthorizationHeader>,
+            Some(body),
+        )
+        .await
+    }
+
+    /// 토큰폐기
+    pub async fn revoke(
+        &self,
+        body: &revoke::RequestBody,
+    ) -> Result<revoke::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::OAuth(OAuthEndpoint::Revoke),
+            revoke::METHOD,
+            None::<AuthorizationHeader>,
+            Some(body),
+        )
+        .await
+    }
+
+    /// 이용기관 설정으로 사용자 토큰(3-legged) 발급
+    /// - code: 사용자인증 성공 후 획득한 Authorization Code
+    pub async fn issue_token(&self, code: String) -> Result<token::ResponseBody, ApiError> {
+        let body = self.require_config()?.token_request(code);
+        self.token(&body).await
+    }
+
+    /// 이용기관 설정으로 이용기관 토큰(2-legged) 발급
+    pub async fn issue_oob_token(&self) -> Result<token::ResponseBody, ApiError> {
+        let body = self.require_config()?.client_credentials_request();
+        self.token(&body).await
+    }
+
+    /// 이용기관 설정으로 토큰폐기
+    pub async fn revoke_token(
+        &self,
+        access_token: String,
+    ) -> Result<revoke::ResponseBody, ApiError> {
+        let body = self.require_config()?.revoke_request(access_token);
+        self.revoke(&body).await
+    }
+
+    /// 요청을 middleware(사용자 middleware, 재시도, 호출량 제한 순)를 거쳐 전송
+    /// - 호출마다 "open_banking.call" tracing span을 남김(필드는 api::trace 참고)
+    pub async fn execute(&self, request: ApiRequest) -> Result<RawResponse, ApiError> {
+        let mut middlewares: Vec<&dyn Middleware> =
+            self.middlewares.iter().map(|m| m.as_ref()).collect();
+        middlewares.push(&self.retry_policy);
+        middlewares.push(self.rate_limiter.as_ref());
+        let span = trace::request_span(&request);
+        let started = Instant::now();
+        let next = Next::new(&middlewares, &self.transport, &span);
+        #[cfg(feature = "metrics")]
+        let next = next.with_metrics(self.metrics.as_deref());
+        let result = next.run(request).instrument(span.clone()).await;
+        trace::record_result(&span, &result, started.elapsed());
+        result
+    }
+}
+
+/// OAuthApi builder
+/// - config: 이용기관 설정(environment를 따로 지정하지 않으면 설정의 환경 사용)
+/// - environment: API 환경
+/// - connect_timeout: 연결 타임아웃
+/// - timeout: 요청 전체(연결부터 응답 본문 수신까지) 타임아웃
+/// - proxy: egress proxy(여러 개 지정 가능)
+/// - root_certificate: 추가로 신뢰할 CA 인증서(여러 개 지정 가능)
+/// - tls_built_in_root_certs: 시스템 기본 CA 인증서 사용 여부(false면 root_certificate만 신뢰)
+/// - user_agent: User-Agent 헤더(미지정 시 "open-banking-api/<버전>")
+/// - client: 직접 구성한 reqwest::Client(지정 시 위 HTTP 설정은 무시됨)
+/// - transport: HTTP 요청을 보낼 tower service(지정 시 위 HTTP 설정과 client는 무시됨)
+/// - middleware: 사용자 middleware(여러 개 지정 가능, 먼저 지정한 middleware가 바깥쪽에서 실행됨)
+/// - retry_policy: 재시도 정책
+/// - rate_limiter: 호출량 제한
+/// - metrics: API 호출 지표(`metrics` feature)
+#[derive(Default)]
+pub struct OAuthApiBuilder {
+    config: Option<ClientConfig>,
+    environment: Option<Environment>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    root_certificates: Vec<reqwest::Certificate>,
+    tls_built_in_root_certs: Option<bool>,
+    user_agent: Option<String>,
+    client: Option<reqwest::Client>,
+    transport: Option<Transport>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    #[cfg(feature = "metrics")]
+    metrics: Option<Arc<Metrics>>,
+}
+
+impl OAuthApiBuilder {
+    pub fn config(mut self, config: ClientConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    pub fn root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    pub fn tls_built_in_root_certs(mut self, enabled: bool) -> Self {
+        self.tls_built_in_root_certs = Some(enabled);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: String) -> Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
+
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    pub fn transport(mut self, transport: Transport) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    pub fn middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// API 호출 지표 설정(호출 결과는 middleware로, 재시도별 결과와 호출량 제한 대기/거절은 각 단계에서 기록)
+    #[cfg(feature = "metrics")]
+    pub fn metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.middlewares.push(metrics.clone());
+        self.metrics = Some(metrics);
+        self
+    }
+
+    pub fn build(self) -> Result<OAuthApi, ApiError> {
+        let transport = match (self.transport, self.client) {
+            (Some(transport), _) => transport,
+            (None, Some(client)) => Transport::reqwest(client),
+            (None, None) => {
+                let mut builder = reqwest::Client::builder().user_agent(
+                    self.user_agent
+                        .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+                );
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
                 }
-                Some(header) => {
-                    let mut header_map = reqwest::header::HeaderMap::new();
-                    for (&k, v) in header.get_hash().iter() {
-                        header_map.insert(k, reqwest::header::HeaderValue::from_str(&v.clone())?);
-                    }
-                    self.client
-                        .get(Into::<String>::into(endpoint))
-                        .headers(header_map)
-                        .send()
-                        .await?
-                        .text()
-                        .await?
+                for certificate in self.root_certificates {
+                    builder = builder.add_root_certificate(certificate);
                 }
-            },
-            HttpMethod::Post => {
-                if body.is_some() {
-                    match header {
-                        None => {
-                            self.client
-                                .post(Into::<String>::into(endpoint))
-                                .body(serde_json::to_string(&body.unwrap())?)
-                                .send()
-                                .await?
-                                .text()
-                                .await?
-                        }
-                        Some(header) => {
-                            let mut header_map = reqwest::header::HeaderMap::new();
-                            for (&k, v) in header.get_hash().iter() {
-                                header_map
-                                    .insert(k, reqwest::header::HeaderValue::from_str(&v.clone())?);
-                            }
-                            self.client
-                                .post(Into::<String>::into(endpoint))
-                                .headers(header_map)
-                                .body(serde_json::to_string(&body.unwrap())?)
-                                .send()
-                                .await?
-                                .text()
-                                .await?
-                        }
-                    }
-                } else {
-                    match header {
-                        None => {
-                            self.client
-                                .post(Into::<String>::into(endpoint))
-                                .send()
-                                .await?
-                                .text()
-                                .await?
-                        }
-                        Some(header) => {
-                            let mut header_map = reqwest::header::HeaderMap::new();
-                            for (&k, v) in header.get_hash().iter() {
-                                header_map
-                                    .insert(k, reqwest::header::HeaderValue::from_str(&v.clone())?);
-                            }
-                            self.client
-                                .post(Into::<String>::into(endpoint))
-                                .headers(header_map)
-                                .send()
-                                .await?
-                                .text()
-                                .await?
-                        }
-                    }
+                if let Some(enabled) = self.tls_built_in_root_certs {
+                    builder = builder.tls_built_in_root_certs(enabled);
                 }
+                Transport::reqwest(builder.build()?)
             }
         };
-        let parsed: ResBody = serde_json::from_str(&result)?;
-        Ok(parsed)
+        let environment = self
+            .environment
+            .or(self.config.as_ref().map(ClientConfig::environment))
+            .unwrap_or_default();
+        Ok(OAuthApi {
+            config: self.config,
+            environment,
+            transport,
+            middlewares: self.middlewares,
+            retry_policy: self.retry_policy.unwrap_or_default(),
+            rate_limiter: self
+                .rate_limiter
+                .unwrap_or_else(|| Arc::new(RateLimiter::new())),
+            #[cfg(feature = "metrics")]
+            metrics: self.metrics,
+        })
     }
 }
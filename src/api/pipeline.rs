@@ -0,0 +1,396 @@
+#[cfg(feature = "metrics")]
+use super::metrics::Metrics;
+use super::redact::{error_body, is_secret_header, redact_form, redact_json, RedactedHeaders};
+use super::transport::Transport;
+use crate::types::{
+    endpoint::{Endpoint, OAuthEndpoint},
+    response_code::{BankResponseCode, ResponseCode},
+    GetHash, HttpMethod,
+};
+use crate::ApiError;
+use reqwest::{
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+    StatusCode,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{fmt, future::Future, pin::Pin, sync::Arc, time::Duration, time::Instant};
+use tracing::Span;
+
+const CONTENT_TYPE_JSON: &str = "application/json; charset=UTF-8";
+const CONTENT_TYPE_FORM: &str = "application/x-www-form-urlencoded; charset=UTF-8";
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// 요청 본문 인코딩 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Json,  // application/json 본문
+    Form,  // application/x-www-form-urlencoded 본문(OAuth 토큰 API)
+    Query, // URL query parameter(GET API)
+}
+
+impl Encoding {
+    pub fn for_endpoint(endpoint: &Endpoint, method: &HttpMethod) -> Self {
+        match (endpoint, method) {
+            (_, HttpMethod::Get) => Self::Query,
+            (Endpoint::OAuth(OAuthEndpoint::Token | OAuthEndpoint::Revoke), _) => Self::Form,
+            _ => Self::Json,
+        }
+    }
+}
+
+/// 파이프라인을 지나는 HTTP 요청
+/// - endpoint: 호출할 API
+/// - method: HTTP method
+/// - url: query parameter를 제외한 URL
+/// - headers: 요청 헤더(인증 헤더 포함, 인증 헤더는 sensitive로 표시됨)
+/// - query: 인코딩된 URL query parameter
+/// - body: 인코딩된 요청 본문
+///
+/// Debug 출력 시 인증 헤더와 본문의 비밀정보는 가려짐
+#[derive(Clone)]
+pub struct ApiRequest {
+    pub endpoint: Endpoint,
+    pub method: HttpMethod,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub query: Option<String>,
+    pub body: Option<String>,
+}
+
+impl ApiRequest {
+    /// 헤더를 HTTP 헤더로 옮기고 요청 본문을 endpoint에 맞게 인코딩
+    pub fn new<ReqHeader, ReqBody>(
+        endpoint: Endpoint,
+        method: HttpMethod,
+        header: Option<&ReqHeader>,
+        body: Option<&ReqBody>,
+    ) -> Result<Self, ApiError>
+    where
+        ReqHeader: GetHash,
+        ReqBody: Serialize,
+    {
+        let mut request = Self {
+            url: endpoint.into(),
+            endpoint,
+            method,
+            headers: HeaderMap::new(),
+            query: None,
+            body: None,
+        };
+        if let Some(header) = header {
+            request.insert_headers(header)?;
+        }
+        if let Some(body) = body {
+            request.encode(body)?;
+        }
+        Ok(request)
+    }
+
+    pub fn insert_headers<ReqHeader: GetHash>(
+        &mut self,
+        header: &ReqHeader,
+    ) -> Result<(), ApiError> {
+        for (k, v) in header.get_hash() {
+            let mut value = HeaderValue::from_str(&v)?;
+            value.set_sensitive(is_secret_header(k));
+            self.headers.insert(k, value);
+        }
+        Ok(())
+    }
+
+    fn encode<ReqBody: Serialize>(&mut self, body: &ReqBody) -> Result<(), ApiError> {
+        match Encoding::for_endpoint(&self.endpoint, &self.method) {
+            Encoding::Json => {
+                self.headers
+                    .insert(CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE_JSON));
+                self.body = Some(serde_json::to_string(body)?);
+            }
+            Encoding::Form => {
+                self.headers
+                    .insert(CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE_FORM));
+                self.body = Some(serde_urlencoded::to_string(body)?);
+            }
+            Encoding::Query => {
+                self.query = Some(serde_urlencoded::to_string(body)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 파이프라인을 지난 HTTP 응답
+/// - status: HTTP 상태코드
+/// - headers: 응답 헤더
+/// - body: 응답 본문
+///
+/// Debug 출력 시 본문의 비밀정보는 가려지고 길이가 제한됨
+#[derive(Clone)]
+pub struct RawResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// 실패한 응답의 본문(ApiError::OpenBanking)
+/// - Debug/Display 출력 시 비밀정보는 가려지고 길이가 제한됨
+/// - decode로 원래 본문을 typed response body로 역직렬화할 수 있음(일부 건이 실패한 입금이체의 건별 결과 등)
+#[derive(Clone)]
+pub struct RawBody(String);
+
+impl RawBody {
+    /// 원래 본문을 typed response body로 역직렬화
+    pub fn decode<ResBody: DeserializeOwned>(&self) -> Result<ResBody, serde_json::Error> {
+        serde_json::from_str(&self.0)
+    }
+}
+
+impl fmt::Debug for RawBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&error_body(&self.0), f)
+    }
+}
+
+impl fmt::Display for RawBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&error_body(&self.0))
+    }
+}
+
+/// 모든 API 응답에 공통으로 들어있는 응답코드 필드
+#[derive(Deserialize)]
+struct Envelope {
+    rsp_code: Option<ResponseCode>,
+    rsp_message: Option<String>,
+    bank_rsp_code: Option<BankResponseCode>,
+    api_tran_id: Option<String>,
+}
+
+impl RawResponse {
+    /// HTTP 상태코드와 응답코드를 확인하여 실패한 응답을 ApiError로 바꿈
+    pub fn check(self) -> Result<Self, ApiError> {
+        if !self.status.is_success() {
+            return Err(ApiError::Http {
+                status: self.status,
+                headers: Box::new(self.headers),
+                body: error_body(&self.body),
+            });
+        }
+        // 응답코드가 없거나 JSON이 아닌 응답은 typed response body로 역직렬화할 때 판단
+        if let Ok(Envelope {
+            rsp_code: Some(rsp_code),
+            rsp_message,
+            bank_rsp_code,
+            api_tran_id,
+        }) = serde_json::from_str(&self.body)
+        {
+            if !rsp_code.is_success() {
+                return Err(ApiError::OpenBanking {
+                    rsp_code,
+                    rsp_message: rsp_message.unwrap_or_default(),
+                    bank_rsp_code,
+                    api_tran_id,
+                    raw: RawBody(self.body),
+                });
+            }
+        }
+        Ok(self)
+    }
+
+    /// 응답 본문을 typed response body로 역직렬화
+    pub fn decode<ResBody: DeserializeOwned>(self) -> Result<ResBody, ApiError> {
+        serde_json::from_str(&self.body).map_err(|source| ApiError::Decode {
+            source,
+            status: self.status,
+            headers: Box::new(self.headers),
+            body: error_body(&self.body),
+        })
+    }
+}
+
+/// 요청 파이프라인의 middleware
+/// - 요청을 바꾸거나(인증/헤더), 다음 단계를 여러 번 호출하거나(재시도), 호출하지 않고 오류를 반환(호출량 제한)할 수 있음
+pub trait Middleware: Send + Sync {
+    fn handle<'a>(
+        &'a self,
+        request: ApiRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<RawResponse, ApiError>>;
+}
+
+/// 파이프라인의 다음 단계(남은 middleware를 거쳐 transport로 전송)
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middlewares: &'a [&'a dyn Middleware],
+    transport: &'a Transport,
+    span: &'a Span,
+    #[cfg(feature = "metrics")]
+    metrics: Option<&'a Metrics>,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        middlewares: &'a [&'a dyn Middleware],
+        transport: &'a Transport,
+        span: &'a Span,
+    ) -> Self {
+        Self {
+            middlewares,
+            transport,
+            span,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    pub(crate) fn with_metrics(mut self, metrics: Option<&'a Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// 이 API 호출의 tracing span("open_banking.call")
+    pub fn span(&self) -> &'a Span {
+        self.span
+    }
+
+    /// 재시도/호출량 제한 지표를 기록할 Metrics(OAuthApiBuilder::metrics로 설정)
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> Option<&'a Metrics> {
+        self.metrics
+    }
+
+    pub fn run(self, request: ApiRequest) -> BoxFuture<'a, Result<RawResponse, ApiError>> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => middleware.handle(
+                request,
+                Next {
+                    middlewares: rest,
+                    ..self
+                },
+            ),
+            None => Box::pin(async move { send(self.transport, request).await?.check() }),
+        }
+    }
+}
+
+impl ApiRequest {
+    /// transport에 넘길 HTTP 요청으로 변환
+    pub fn into_http(self) -> Result<http::Request<String>, ApiError> {
+        let url = match self.query {
+            Some(query) if !query.is_empty() => format!("{}?{}", self.url, query),
+            _ => self.url,
+        };
+        let method = match self.method {
+            HttpMethod::Get => http::Method::GET,
+            HttpMethod::Post => http::Method::POST,
+        };
+        let mut request = http::Request::builder()
+            .method(method)
+            .uri(url)
+            .body(self.body.unwrap_or_default())
+            .map_err(|e| ApiError::Transport(e.into()))?;
+        *request.headers_mut() = self.headers;
+        Ok(request)
+    }
+}
+
+impl From<http::Response<String>> for RawResponse {
+    fn from(response: http::Response<String>) -> Self {
+        let (parts, body) = response.into_parts();
+        Self {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        }
+    }
+}
+
+impl fmt::Debug for ApiRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let body = self.body.as_deref().map(|body| {
+            match Encoding::for_endpoint(&self.endpoint, &self.method) {
+                Encoding::Form => redact_form(body),
+                _ => redact_json(body),
+            }
+        });
+        f.debug_struct("ApiRequest")
+            .field("endpoint", &self.endpoint)
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("headers", &RedactedHeaders(&self.headers))
+            .field("query", &self.query.as_deref().map(redact_form))
+            .field("body", &body)
+            .finish()
+    }
+}
+
+impl fmt::Debug for RawResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawResponse")
+            .field("status", &self.status)
+            .field("headers", &RedactedHeaders(&self.headers))
+            .field("body", &error_body(&self.body))
+            .finish()
+    }
+}
+
+async fn send(transport: &Transport, request: ApiRequest) -> Result<RawResponse, ApiError> {
+    let response = transport.send(request.into_http()?).await?;
+    Ok(response.into())
+}
+
+/// 요청 요약 정보로 로그를 남기는 middleware(헤더/본문은 넘기지 않으므로 토큰 등 비밀정보가 로그에 남지 않음)
+pub struct Logger<F> {
+    log: F,
+}
+
+/// Logger middleware가 넘기는 요청 요약 정보
+/// - endpoint: 호출한 API
+/// - status: HTTP 상태코드(응답을 받지 못한 경우 None)
+/// - elapsed: 소요시간
+/// - error: 실패한 경우의 오류
+pub struct LogEntry<'a> {
+    pub endpoint: &'a Endpoint,
+    pub status: Option<StatusCode>,
+    pub elapsed: Duration,
+    pub error: Option<&'a ApiError>,
+}
+
+impl<F> Logger<F>
+where
+    F: Fn(&LogEntry<'_>) + Send + Sync,
+{
+    pub fn new(log: F) -> Arc<Self> {
+        Arc::new(Self { log })
+    }
+}
+
+impl<F> Middleware for Logger<F>
+where
+    F: Fn(&LogEntry<'_>) + Send + Sync,
+{
+    fn handle<'a>(
+        &'a self,
+        request: ApiRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<RawResponse, ApiError>> {
+        Box::pin(async move {
+            let endpoint = request.endpoint;
+            let started = Instant::now();
+            let result = next.run(request).await;
+            let (status, error) = match &result {
+                Ok(response) => (Some(response.status), None),
+                Err(e @ ApiError::Http { status, .. }) => (Some(*status), Some(e)),
+                Err(e) => (None, Some(e)),
+            };
+            (self.log)(&LogEntry {
+                endpoint: &endpoint,
+                status,
+                elapsed: started.elapsed(),
+                error,
+            });
+            result
+        })
+    }
+}
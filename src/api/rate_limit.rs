@@ -0,0 +1,300 @@
+use super::pipeline::{ApiRequest, BoxFuture, Middleware, Next, RawResponse};
+use crate::types::endpoint::EndpointGroup;
+use crate::ApiError;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// 호출량 제한에 걸린 요청의 처리 방식
+/// - Queue 모드에서 대기할 수 있는 요청은 버킷 크기(capacity)만큼이고, 넘치면 ApiError::RateLimited 반환
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleMode {
+    Queue,  // 토큰이 채워질 때까지 대기 후 호출
+    Reject, // 호출하지 않고 ApiError::RateLimited 반환
+}
+
+/// API 그룹별 token bucket 설정
+/// - capacity: 버킷 크기(순간적으로 허용하는 최대 호출 수)
+/// - refill_per_sec: 초당 채워지는 토큰 수(지속적으로 허용하는 초당 호출 수)
+/// - mode: 호출량 제한에 걸린 요청의 처리 방식
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    pub capacity: u32,
+    pub refill_per_sec: f64,
+    pub mode: ThrottleMode,
+}
+
+/// API 그룹별 호출량 제한 통계
+/// - allowed: 바로 호출된 요청 수
+/// - queued: 대기 후 호출된 요청 수
+/// - rejected: 거절된 요청 수
+/// - waited: 대기한 총 시간
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThrottleStats {
+    pub allowed: u64,
+    pub queued: u64,
+    pub rejected: u64,
+    pub waited: Duration,
+}
+
+struct Bucket {
+    config: RateLimit,
+    state: Mutex<BucketState>,
+    allowed: AtomicU64,
+    queued: AtomicU64,
+    rejected: AtomicU64,
+    waited_nanos: AtomicU64,
+}
+
+struct BucketState {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(config: RateLimit) -> Self {
+        Self {
+            state: Mutex::new(BucketState {
+                tokens: config.capacity as f64,
+                updated_at: Instant::now(),
+            }),
+            config,
+            allowed: AtomicU64::new(0),
+            queued: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+            waited_nanos: AtomicU64::new(0),
+        }
+    }
+
+    /// 토큰 하나를 가져가고 호출 전 대기해야 할 시간을 반환(Reject 모드에서 토큰이 없으면 None)
+    fn take(&self) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.updated_at).as_secs_f64();
+        state.tokens =
+            (state.tokens + elapsed * self.config.refill_per_sec).min(self.config.capacity as f64);
+        state.updated_at = now;
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            self.allowed.fetch_add(1, Ordering::Relaxed);
+            return Some(Duration::ZERO);
+        }
+        // 토큰이 다시 채워지지 않는 설정이거나 대기열이 가득 차면 거절
+        let queue_full = state.tokens - 1.0 < -(self.config.capacity as f64);
+        let mode = match self.config.refill_per_sec > 0.0 && !queue_full {
+            true => self.config.mode,
+            false => ThrottleMode::Reject,
+        };
+        match mode {
+            ThrottleMode::Reject => {
+                self.rejected.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+            ThrottleMode::Queue => {
+                // 앞서 대기 중인 요청들의 몫까지 미리 빼 두어 대기 순서대로 호출되도록 함
+                let wait = (1.0 - state.tokens) / self.config.refill_per_sec;
+                state.tokens -= 1.0;
+                let wait = Duration::from_secs_f64(wait.max(0.0));
+                self.queued.fetch_add(1, Ordering::Relaxed);
+                self.waited_nanos
+                    .fetch_add(wait.as_nanos() as u64, Ordering::Relaxed);
+                Some(wait)
+            }
+        }
+    }
+
+    /// 대기하다 취소된 요청이 가져간 토큰과 대기시간 통계를 돌려줌
+    fn refund(&self, wait: Duration) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.tokens = (state.tokens + 1.0).min(self.config.capacity as f64);
+        self.queued.fetch_sub(1, Ordering::Relaxed);
+        self.waited_nanos
+            .fetch_sub(wait.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> ThrottleStats {
+        ThrottleStats {
+            allowed: self.allowed.load(Ordering::Relaxed),
+            queued: self.queued.load(Ordering::Relaxed),
+            rejected: self.rejected.load(Ordering::Relaxed),
+            waited: Duration::from_nanos(self.waited_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// 대기 중인 요청의 토큰(대기가 끝나기 전에 future가 drop되면 토큰을 돌려줌)
+struct QueuedToken<'a> {
+    bucket: &'a Bucket,
+    wait: Duration,
+}
+
+impl Drop for QueuedToken<'_> {
+    fn drop(&mut self) {
+        self.bucket.refund(self.wait);
+    }
+}
+
+/// API 그룹별 token bucket 호출량 제한(설정하지 않은 그룹은 제한하지 않음)
+#[derive(Default)]
+pub struct RateLimiter {
+    buckets: HashMap<EndpointGroup, Bucket>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_limit(mut self, group: EndpointGroup, limit: RateLimit) -> Self {
+        self.buckets.insert(group, Bucket::new(limit));
+        self
+    }
+
+    /// 호출 전 토큰을 가져감(Queue 모드는 토큰이 채워질 때까지 대기)
+    pub async fn acquire(&self, group: EndpointGroup) -> Result<(), ApiError> {
+        self.wait(group).await.map(|_| ())
+    }
+
+    /// 호출 전 토큰을 가져가고 대기한 시간을 반환
+    async fn wait(&self, group: EndpointGroup) -> Result<Duration, ApiError> {
+        let Some(bucket) = self.buckets.get(&group) else {
+            return Ok(Duration::ZERO);
+        };
+        match bucket.take() {
+            Some(wait) if wait.is_zero() => Ok(wait),
+            Some(wait) => {
+                let queued = QueuedToken { bucket, wait };
+                tokio::time::sleep(wait).await;
+                std::mem::forget(queued);
+                Ok(wait)
+            }
+            None => Err(ApiError::RateLimited { group }),
+        }
+    }
+
+    /// API 그룹별 호출량 제한 통계(제한을 설정하지 않은 그룹은 None)
+    pub fn stats(&self, group: EndpointGroup) -> Option<ThrottleStats> {
+        self.buckets.get(&group).map(Bucket::stats)
+    }
+}
+
+impl Middleware for RateLimiter {
+    fn handle<'a>(
+        &'a self,
+        request: ApiRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<RawResponse, ApiError>> {
+        Box::pin(async move {
+            let group = request.endpoint.group();
+            let waited = self.wait(group).await;
+            #[cfg(feature = "metrics")]
+            if let Some(metrics) = next.metrics() {
+                metrics.record_throttle(group, &waited);
+            }
+            waited?;
+            next.run(request).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(capacity: u32, refill_per_sec: f64, mode: ThrottleMode) -> RateLimit {
+        RateLimit {
+            capacity,
+            refill_per_sec,
+            mode,
+        }
+    }
+
+    #[test]
+    fn bucket_allows_bursts_up_to_capacity_then_refills() {
+        let bucket = Bucket::new(limit(2, 100.0, ThrottleMode::Reject));
+        assert_eq!(bucket.take(), Some(Duration::ZERO));
+        assert_eq!(bucket.take(), Some(Duration::ZERO));
+        assert_eq!(bucket.take(), None);
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(bucket.take(), Some(Duration::ZERO));
+        assert_eq!(
+            bucket.stats(),
+            ThrottleStats {
+                allowed: 3,
+                queued: 0,
+                rejected: 1,
+                waited: Duration::ZERO,
+            }
+        );
+    }
+
+    #[test]
+    fn queue_mode_waits_in_order() {
+        let bucket = Bucket::new(limit(2, 10.0, ThrottleMode::Queue));
+        assert_eq!(bucket.take(), Some(Duration::ZERO));
+        assert_eq!(bucket.take(), Some(Duration::ZERO));
+        let first = bucket.take().unwrap();
+        let second = bucket.take().unwrap();
+        assert!(first > Duration::from_millis(50) && first <= Duration::from_millis(100));
+        assert!(second > first + Duration::from_millis(50));
+        let stats = bucket.stats();
+        assert_eq!((stats.allowed, stats.queued, stats.rejected), (2, 2, 0));
+        assert_eq!(stats.waited, first + second);
+    }
+
+    #[test]
+    fn queue_mode_rejects_when_the_queue_is_full() {
+        let bucket = Bucket::new(limit(1, 10.0, ThrottleMode::Queue));
+        assert_eq!(bucket.take(), Some(Duration::ZERO));
+        assert!(bucket.take().is_some());
+        assert_eq!(bucket.take(), None);
+        let stats = bucket.stats();
+        assert_eq!((stats.allowed, stats.queued, stats.rejected), (1, 1, 1));
+    }
+
+    #[tokio::test]
+    async fn cancelled_wait_returns_its_token() {
+        let limiter = RateLimiter::new()
+            .with_limit(EndpointGroup::Inquiry, limit(1, 1.0, ThrottleMode::Queue));
+        limiter.acquire(EndpointGroup::Inquiry).await.unwrap();
+        for _ in 0..3 {
+            let acquire = limiter.acquire(EndpointGroup::Inquiry);
+            assert!(tokio::time::timeout(Duration::from_millis(10), acquire)
+                .await
+                .is_err());
+        }
+        let bucket = &limiter.buckets[&EndpointGroup::Inquiry];
+        let wait = bucket.take().unwrap();
+        assert!(wait <= Duration::from_secs(1), "waited {:?}", wait);
+        let stats = bucket.stats();
+        assert_eq!((stats.allowed, stats.queued, stats.rejected), (1, 1, 0));
+        assert_eq!(stats.waited, wait);
+    }
+
+    #[test]
+    fn queue_mode_without_refill_rejects() {
+        let bucket = Bucket::new(limit(1, 0.0, ThrottleMode::Queue));
+        assert_eq!(bucket.take(), Some(Duration::ZERO));
+        assert_eq!(bucket.take(), None);
+    }
+
+    #[tokio::test]
+    async fn reject_mode_returns_rate_limited() {
+        let limiter = RateLimiter::new()
+            .with_limit(EndpointGroup::Inquiry, limit(1, 0.0, ThrottleMode::Reject));
+        limiter.acquire(EndpointGroup::Inquiry).await.unwrap();
+        assert!(matches!(
+            limiter.acquire(EndpointGroup::Inquiry).await,
+            Err(ApiError::RateLimited {
+                group: EndpointGroup::Inquiry
+            })
+        ));
+        limiter.acquire(EndpointGroup::Transfer).await.unwrap();
+        assert!(limiter.stats(EndpointGroup::Transfer).is_none());
+    }
+}
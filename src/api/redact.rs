@@ -0,0 +1,107 @@
+//! 로그/오류/녹화 파일에 남기기 전에 요청/응답의 비밀정보를 가림
+//! - 헤더: Authorization, Kftc-Bfop-*(사용자일련번호, CI, Access Token)
+//! - 본문: 토큰, Client Secret, 인가코드, CI, 암호문구, 계좌번호(이름이 "account_num"으로 끝나는 모든 필드)
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::fmt;
+
+pub(crate) const REDACTED: &str = "[REDACTED]";
+/// 오류에 담을 응답 본문의 최대 길이(바이트)
+const MAX_ERROR_BODY_LEN: usize = 4096;
+/// 값을 가리는 필드(계좌번호는 이름이 "account_num"으로 끝나는 모든 필드)
+const SECRET_FIELDS: [&str; 8] = [
+    "access_token",
+    "refresh_token",
+    "client_secret",
+    "code",
+    "user_ci",
+    "account_holder_info",
+    "wd_pass_phrase",
+    "user_connection_info",
+];
+
+/// 값을 가려야 하는 헤더인지 여부
+pub(crate) fn is_secret_header(name: &str) -> bool {
+    name.eq_ignore_ascii_case("authorization")
+        || name
+            .get(..10)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("kftc-bfop-"))
+}
+
+fn is_secret(field: &str) -> bool {
+    SECRET_FIELDS.contains(&field) || field.ends_with("account_num")
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (field, value) in fields.iter_mut() {
+                if is_secret(field) && !value.is_null() {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact_value(value);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+/// JSON 본문의 비밀정보를 가림(JSON이 아니면 그대로 둠)
+pub(crate) fn redact_json(body: &str) -> String {
+    match serde_json::from_str::<Value>(body) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => body.to_string(),
+    }
+}
+
+/// form/query 문자열의 비밀정보를 가림
+pub(crate) fn redact_form(encoded: &str) -> String {
+    let pairs: Vec<(String, String)> = serde_urlencoded::from_str(encoded).unwrap_or_default();
+    let pairs: Vec<(String, String)> = pairs
+        .into_iter()
+        .map(|(field, value)| {
+            if is_secret(&field) {
+                (field, REDACTED.to_string())
+            } else {
+                (field, value)
+            }
+        })
+        .collect();
+    serde_urlencoded::to_string(pairs).unwrap_or_default()
+}
+
+/// 오류에 담을 응답 본문(비밀정보를 가리고 MAX_ERROR_BODY_LEN 이내로 자름)
+pub(crate) fn error_body(body: &str) -> String {
+    let mut body = redact_json(body);
+    if body.len() > MAX_ERROR_BODY_LEN {
+        let mut end = MAX_ERROR_BODY_LEN;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        body.truncate(end);
+        body.push_str("...(truncated)");
+    }
+    body
+}
+
+/// 비밀 헤더의 값을 가린 헤더 목록(Debug 출력용)
+pub(crate) struct RedactedHeaders<'a>(pub(crate) &'a HeaderMap);
+
+impl fmt::Debug for RedactedHeaders<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(name, value)| {
+                let value = match is_secret_header(name.as_str()) || value.is_sensitive() {
+                    true => REDACTED,
+                    false => value.to_str().unwrap_or("[non-ascii]"),
+                };
+                (name.as_str(), value)
+            }))
+            .finish()
+    }
+}
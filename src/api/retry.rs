@@ -0,0 +1,155 @@
+use super::pipeline::{ApiRequest, BoxFuture, Middleware, Next, RawResponse};
+use crate::ApiError;
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// API 호출 재시도 정책
+/// - max_attempts: 최초 호출을 포함한 최대 호출횟수(1이면 재시도하지 않음)
+/// - initial_backoff: 첫 재시도 전 대기시간
+/// - max_backoff: 재시도 간 최대 대기시간(대기시간은 재시도마다 multiplier배로 늘어남)
+/// - multiplier: 대기시간 증가 배수
+/// - jitter: 대기시간에 더하거나 빼는 무작위 비율(0.0 ~ 1.0)
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: 0.2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 재시도하지 않는 정책
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// attempt번째 호출이 실패했을 때 재시도할지 여부
+    pub fn should_retry(&self, attempt: u32, error: &ApiError) -> bool {
+        attempt < self.max_attempts && error.is_retryable()
+    }
+
+    /// attempt번째 호출이 실패한 뒤 다음 재시도까지의 대기시간
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let backoff = backoff.min(self.max_backoff.as_secs_f64());
+        let jitter = self.jitter.clamp(0.0, 1.0) * (random_unit() * 2.0 - 1.0);
+        Duration::from_secs_f64((backoff * (1.0 + jitter)).max(0.0))
+    }
+}
+
+impl Middleware for RetryPolicy {
+    fn handle<'a>(
+        &'a self,
+        request: ApiRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<RawResponse, ApiError>> {
+        Box::pin(async move {
+            // bank_tran_id를 담는 요청은 같은 bank_tran_id로 다시 보내면 안 됨(이체는 이체결과조회로 확인)
+            if !request.endpoint.is_idempotent() {
+                #[cfg(feature = "metrics")]
+                let endpoint = request.endpoint;
+                let result = next.run(request).await;
+                #[cfg(feature = "metrics")]
+                if let Some(metrics) = next.metrics() {
+                    metrics.record_attempt(&endpoint, &result);
+                }
+                return result;
+            }
+            let mut attempt = 1;
+            loop {
+                let result = next.run(request.clone()).await;
+                #[cfg(feature = "metrics")]
+                if let Some(metrics) = next.metrics() {
+                    metrics.record_attempt(&request.endpoint, &result);
+                }
+                match result {
+                    Err(e) if self.should_retry(attempt, &e) => {
+                        tokio::time::sleep(self.backoff(attempt)).await;
+                        super::trace::record_retry(next.span(), attempt);
+                        attempt += 1;
+                    }
+                    result => return result,
+                }
+            }
+        })
+    }
+}
+
+/// 0.0 ~ 1.0 사이의 난수(jitter 용도로만 사용)
+fn random_unit() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::endpoint::EndpointGroup;
+
+    fn policy(jitter: f64) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            multiplier: 2.0,
+            jitter,
+        }
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_max_backoff() {
+        let policy = policy(0.0);
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn backoff_jitter_stays_within_ratio() {
+        let policy = policy(0.2);
+        for _ in 0..100 {
+            let backoff = policy.backoff(2);
+            assert!(backoff >= Duration::from_millis(160) && backoff <= Duration::from_millis(240));
+        }
+    }
+
+    #[test]
+    fn only_retryable_errors_are_retried_up_to_max_attempts() {
+        let policy = policy(0.0);
+        let throttled = ApiError::Http {
+            status: reqwest::StatusCode::TOO_MANY_REQUESTS,
+            headers: Box::default(),
+            body: String::new(),
+        };
+        let rejected = ApiError::RateLimited {
+            group: EndpointGroup::Inquiry,
+        };
+        assert!(policy.should_retry(1, &throttled));
+        assert!(policy.should_retry(3, &throttled));
+        assert!(!policy.should_retry(4, &throttled));
+        assert!(!policy.should_retry(1, &rejected));
+        assert!(!RetryPolicy::none().should_retry(1, &throttled));
+    }
+}
@@ -0,0 +1,126 @@
+//! API 호출별 tracing span
+//! - span 이름: "open_banking.call"
+//! - 필드: endpoint, method, bank_tran_id, api_tran_id, rsp_code, bank_rsp_code, status, latency_ms, retry_count
+//! - 요청/응답 본문과 헤더는 남기지 않으므로 토큰, Client Secret, CI, 계좌번호는 span에 포함되지 않음
+use super::pipeline::{ApiRequest, RawResponse};
+use crate::ApiError;
+use serde_json::Value;
+use std::time::Duration;
+use tracing::{field::Empty, Span};
+
+/// 요청 span 생성(bank_tran_id는 요청 본문에서 찾고, 입금이체처럼 여러 건이면 ","로 이어 붙임)
+pub(crate) fn request_span(request: &ApiRequest) -> Span {
+    let mut bank_tran_ids = Vec::new();
+    if let Some(body) = request.body.as_deref() {
+        match serde_json::from_str::<Value>(body) {
+            Ok(body) => collect_bank_tran_ids(&body, &mut bank_tran_ids),
+            Err(_) => collect_form_bank_tran_ids(body, &mut bank_tran_ids),
+        }
+    }
+    if let Some(query) = request.query.as_deref() {
+        collect_form_bank_tran_ids(query, &mut bank_tran_ids);
+    }
+    tracing::info_span!(
+        "open_banking.call",
+        endpoint = ?request.endpoint,
+        method = ?request.method,
+        bank_tran_id = bank_tran_ids.join(","),
+        api_tran_id = Empty,
+        rsp_code = Empty,
+        bank_rsp_code = Empty,
+        status = Empty,
+        latency_ms = Empty,
+        retry_count = 0u32,
+    )
+}
+
+/// 응답(또는 오류)의 응답코드, HTTP 상태코드, 소요시간을 span에 기록하고 완료 event를 남김
+pub(crate) fn record_result(
+    span: &Span,
+    result: &Result<RawResponse, ApiError>,
+    elapsed: Duration,
+) {
+    span.record("latency_ms", elapsed.as_millis() as u64);
+    let (status, body) = match result {
+        Ok(response) => (Some(response.status), Some(response.body.as_str())),
+        Err(ApiError::Http { status, body, .. } | ApiError::Decode { status, body, .. }) => {
+            (Some(*status), Some(body.as_str()))
+        }
+        Err(ApiError::OpenBanking {
+            rsp_code,
+            bank_rsp_code,
+            api_tran_id,
+            ..
+        }) => {
+            span.record("rsp_code", rsp_code.code());
+            if let Some(bank_rsp_code) = bank_rsp_code {
+                span.record("bank_rsp_code", bank_rsp_code.code());
+            }
+            if let Some(api_tran_id) = api_tran_id {
+                span.record("api_tran_id", api_tran_id.as_str());
+            }
+            (None, None)
+        }
+        Err(_) => (None, None),
+    };
+    if let Some(status) = status {
+        span.record("status", status.as_u16());
+    }
+    if let Some(Ok(Value::Object(body))) = body.map(serde_json::from_str::<Value>) {
+        for field in ["api_tran_id", "rsp_code", "bank_rsp_code"] {
+            if let Some(Value::String(value)) = body.get(field) {
+                span.record(field, value.as_str());
+            }
+        }
+    }
+    span.in_scope(|| match result {
+        Ok(_) => tracing::info!("open banking API call succeeded"),
+        Err(e) => tracing::warn!(error = %error_summary(e), "open banking API call failed"),
+    });
+}
+
+/// API 호출 span에 재시도 횟수를 기록
+pub(crate) fn record_retry(span: &Span, retry_count: u32) {
+    span.record("retry_count", retry_count);
+}
+
+/// 응답 본문을 뺀 오류 요약(본문은 log에 남기지 않음)
+fn error_summary(error: &ApiError) -> String {
+    match error {
+        ApiError::Http { status, .. } => format!("open banking API responded with HTTP {}", status),
+        ApiError::Decode { source, status, .. } => format!(
+            "failed to decode open banking API response (HTTP {}): {}",
+            status, source
+        ),
+        e => e.to_string(),
+    }
+}
+
+fn collect_bank_tran_ids(value: &Value, bank_tran_ids: &mut Vec<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (field, value) in fields {
+                match value {
+                    Value::String(id) if field == "bank_tran_id" => bank_tran_ids.push(id.clone()),
+                    _ => collect_bank_tran_ids(value, bank_tran_ids),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_bank_tran_ids(item, bank_tran_ids);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_form_bank_tran_ids(encoded: &str, bank_tran_ids: &mut Vec<String>) {
+    let pairs: Vec<(String, String)> = serde_urlencoded::from_str(encoded).unwrap_or_default();
+    bank_tran_ids.extend(
+        pairs
+            .into_iter()
+            .filter(|(field, _)| field == "bank_tran_id")
+            .map(|(_, value)| value),
+    );
+}
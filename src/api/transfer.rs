@@ -0,0 +1,194 @@
+use super::oauth::OAuthApi;
+use crate::types::{
+    datetime::TranDtime,
+    endpoint::{Endpoint, TransferEndpoint},
+    transfer::{deposit, result, withdraw},
+    AuthorizationHeader,
+};
+use crate::ApiError;
+use std::time::Duration;
+
+/// 입금이체 일괄처리 결과
+/// - succeeded: 참가기관 정상처리된 입금 결과
+/// - failed: 참가기관이 거절한 입금 결과
+/// - pending: 참가기관이 처리중 등으로 응답해 처리결과를 알 수 없는 입금 결과(이체결과조회 필요)
+/// - unreported: 응답의 res_list에 결과가 없는 입금요청(이체결과조회 필요)
+/// - rejected: API가 건별 결과 없이 요청 전체를 거절한 요청
+/// - unresolved: 처리결과를 알 수 없는 요청(이체결과조회 필요)
+#[derive(Debug, Default)]
+pub struct DepositBatchOutcome {
+    pub succeeded: Vec<deposit::ResponseItem>,
+    pub failed: Vec<deposit::ResponseItem>,
+    pub pending: Vec<deposit::ResponseItem>,
+    pub unreported: Vec<deposit::RequestItem>,
+    pub rejected: Vec<(deposit::RequestBody, ApiError)>,
+    pub unresolved: Vec<(deposit::RequestBody, ApiError)>,
+}
+
+impl DepositBatchOutcome {
+    /// 입금이체 응답의 건별 결과를 분류하고, 결과가 없는 요청은 unreported에 모음
+    fn push_res_list(&mut self, body: &deposit::RequestBody, res_list: Vec<deposit::ResponseItem>) {
+        self.unreported.extend(
+            body.req_list()
+                .iter()
+                .filter(|req| {
+                    !res_list
+                        .iter()
+                        .any(|res| res.bank_tran_id() == req.bank_tran_id())
+                })
+                .cloned(),
+        );
+        for item in res_list {
+            if item.is_success() {
+                self.succeeded.push(item);
+            } else if item.is_outcome_unknown() {
+                self.pending.push(item);
+            } else {
+                self.failed.push(item);
+            }
+        }
+    }
+}
+
+/// 실패한 입금이체 응답에 담긴 건별 결과(건별 결과가 없으면 None)
+fn deposit_res_list(error: &ApiError) -> Option<Vec<deposit::ResponseItem>> {
+    match error {
+        ApiError::OpenBanking { raw, .. } => raw
+            .decode::<deposit::ResponseBody>()
+            .ok()
+            .map(deposit::ResponseBody::into_res_list)
+            .filter(|res_list| !res_list.is_empty()),
+        _ => None,
+    }
+}
+
+/// 처리결과를 알 수 없는 이체에 대해 이체결과조회를 반복하는 주기
+/// - initial_delay: 첫 조회 전 대기시간
+/// - max_delay: 조회 간 최대 대기시간(대기시간은 매 조회마다 두 배로 늘어남)
+/// - max_attempts: 최대 조회횟수
+#[derive(Debug, Clone)]
+pub struct TransferResolver {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub max_attempts: u32,
+}
+
+impl Default for TransferResolver {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            max_attempts: 10,
+        }
+    }
+}
+
+impl OAuthApi {
+    /// 출금이체(핀테크이용번호/계좌번호 사용)
+    /// - access_token: "transfer" scope을 포함한 사용자 토큰
+    pub async fn withdraw(
+        &self,
+        access_token: String,
+        body: &withdraw::RequestBody,
+    ) -> Result<withdraw::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::Transfer(body.endpoint()),
+            withdraw::METHOD,
+            Some(AuthorizationHeader::new(access_token)),
+            Some(body),
+        )
+        .await
+    }
+
+    /// 입금이체(핀테크이용번호/계좌번호 사용)
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub async fn deposit(
+        &self,
+        access_token: String,
+        body: &deposit::RequestBody,
+    ) -> Result<deposit::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::Transfer(body.endpoint()),
+            deposit::METHOD,
+            Some(AuthorizationHeader::new(access_token)),
+            Some(body),
+        )
+        .await
+    }
+
+    /// 입금이체 일괄처리(deposit::RequestBody::batches로 나눈 요청을 차례로 호출하고 건별 결과를 모음)
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub async fn deposit_batch(
+        &self,
+        access_token: String,
+        bodies: Vec<deposit::RequestBody>,
+    ) -> DepositBatchOutcome {
+        let mut outcome = DepositBatchOutcome::default();
+        for body in bodies {
+            match self.deposit(access_token.clone(), &body).await {
+                Ok(response) => outcome.push_res_list(&body, response.into_res_list()),
+                // 일부 건이 실패하면 응답코드(API)는 실패이지만 res_list에 건별 결과가 담겨 있음
+                Err(e) => match deposit_res_list(&e) {
+                    Some(res_list) => outcome.push_res_list(&body, res_list),
+                    None if e.is_outcome_unknown() => outcome.unresolved.push((body, e)),
+                    None => outcome.rejected.push((body, e)),
+                },
+            }
+        }
+        outcome
+    }
+
+    /// 이체결과조회
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub async fn transfer_result(
+        &self,
+        access_token: String,
+        body: &result::RequestBody,
+    ) -> Result<result::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::Transfer(TransferEndpoint::Result),
+            result::METHOD,
+            Some(AuthorizationHeader::new(access_token)),
+            Some(body),
+        )
+        .await
+    }
+
+    /// 처리결과를 알 수 없는 이체(타임아웃, 처리중 응답 등)의 결과가 확정될 때까지 이체결과조회를 반복
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    /// - check_type: 원거래의 종류(출금이체/입금이체)
+    /// - item: 원거래 정보
+    pub async fn resolve_transfer(
+        &self,
+        access_token: String,
+        check_type: result::CheckType,
+        item: result::RequestItem,
+        resolver: &TransferResolver,
+    ) -> Result<result::ResponseItem, ApiError> {
+        let mut delay = resolver.initial_delay;
+        for _ in 0..resolver.max_attempts {
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(resolver.max_delay);
+            let body =
+                result::RequestBody::new(check_type.clone(), TranDtime::now(), vec![item.clone()]);
+            let response = match self.transfer_result(access_token.clone(), &body).await {
+                Ok(response) => response,
+                Err(e) if e.is_outcome_unknown() => continue,
+                Err(e) => return Err(e),
+            };
+            if let Some(res) = response
+                .into_res_list()
+                .into_iter()
+                .find(|res| res.org_bank_tran_id() == item.org_bank_tran_id())
+            {
+                if res.is_final() {
+                    return Ok(res);
+                }
+            }
+        }
+        Err(ApiError::TransferUnresolved {
+            org_bank_tran_id: item.org_bank_tran_id().to_string(),
+            attempts: resolver.max_attempts,
+        })
+    }
+}
@@ -0,0 +1,128 @@
+use super::pipeline::BoxFuture;
+use crate::ApiError;
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::sync::Mutex;
+use tower::{util::BoxService, BoxError, Service, ServiceExt};
+
+/// transport에 넘기는 HTTP 요청(본문은 인코딩된 문자열)
+pub type HttpRequest = http::Request<String>;
+/// transport가 돌려주는 HTTP 응답(본문은 문자열)
+pub type HttpResponse = http::Response<String>;
+
+/// HTTP 요청을 실제로 보내는 `tower::Service`
+/// - 기본값은 reqwest를 이용하는 ReqwestService
+/// - tower layer(timeout, concurrency limit, load shed 등)를 씌운 service나 테스트용 fake service로 바꿀 수 있음
+///
+/// 모든 요청은 하나의 service 인스턴스에서 `poll_ready` 후 `call`되므로(복제한 Transport도 같은 인스턴스를 공유)
+/// concurrency limit, circuit breaker 등 상태를 가진 layer가 전체 요청에 적용됨.
+/// service가 준비될 때까지 기다리는 요청은 도착한 순서대로 처리됨
+#[derive(Clone)]
+pub struct Transport {
+    service: Arc<Mutex<BoxService<HttpRequest, HttpResponse, BoxError>>>,
+}
+
+impl Transport {
+    pub fn new<S>(service: S) -> Self
+    where
+        S: Service<HttpRequest, Response = HttpResponse> + Send + 'static,
+        S::Error: Into<BoxError>,
+        S::Future: Send + 'static,
+    {
+        Self {
+            service: Arc::new(Mutex::new(BoxService::new(service.map_err(Into::into)))),
+        }
+    }
+
+    pub fn reqwest(client: reqwest::Client) -> Self {
+        Self::new(ReqwestService::new(client))
+    }
+
+    pub(crate) async fn send(&self, request: HttpRequest) -> Result<HttpResponse, ApiError> {
+        // 준비된 service에서 요청을 시작한 뒤에는 lock을 풀어 응답을 기다리는 동안 다른 요청이 진행되도록 함
+        let response = {
+            let mut service = self.service.lock().await;
+            service.ready().await.map_err(into_api_error)?.call(request)
+        };
+        response.await.map_err(into_api_error)
+    }
+}
+
+impl Default for Transport {
+    fn default() -> Self {
+        Self::reqwest(reqwest::Client::new())
+    }
+}
+
+/// service가 반환한 오류를 ApiError로 바꿈(reqwest/ApiError 오류는 원래 variant를 유지하여 재시도 판단에 사용)
+fn into_api_error(error: BoxError) -> ApiError {
+    let error = match error.downcast::<ApiError>() {
+        Ok(error) => return *error,
+        Err(error) => error,
+    };
+    match error.downcast::<reqwest::Error>() {
+        Ok(error) => ApiError::ReqwestError(*error),
+        Err(error) => ApiError::Transport(error),
+    }
+}
+
+/// reqwest::Client로 HTTP 요청을 보내는 service
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestService {
+    client: reqwest::Client,
+}
+
+impl ReqwestService {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Service<HttpRequest> for ReqwestService {
+    type Response = HttpResponse;
+    type Error = reqwest::Error;
+    type Future = BoxFuture<'static, Result<HttpResponse, reqwest::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let client = self.client.clone();
+        Box::pin(async move {
+            let response = client.execute(request.try_into()?).await?;
+            let mut builder = http::Response::builder().status(response.status());
+            if let Some(headers) = builder.headers_mut() {
+                *headers = response.headers().clone();
+            }
+            let body = response.text().await?;
+            Ok(builder
+                .body(body)
+                .expect("status and headers come from a valid response"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn requests_share_one_service_instance() {
+        let mut calls = 0;
+        let transport = Transport::new(tower::service_fn(move |_: HttpRequest| {
+            calls += 1;
+            let body = calls.to_string();
+            async move { Ok::<_, BoxError>(HttpResponse::new(body)) }
+        }));
+        let cloned = transport.clone();
+        let mut bodies = Vec::new();
+        for transport in [&transport, &cloned, &transport] {
+            let response = transport.send(HttpRequest::default()).await.unwrap();
+            bodies.push(response.into_body());
+        }
+        assert_eq!(bodies, ["1", "2", "3"]);
+    }
+}
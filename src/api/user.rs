@@ -0,0 +1,25 @@
+use super::oauth::OAuthApi;
+use crate::types::{
+    endpoint::{Endpoint, UserEndpoint},
+    user::user_info,
+    AuthorizationHeader,
+};
+use crate::ApiError;
+
+impl OAuthApi {
+    /// 사용자정보조회
+    /// - access_token: "login" scope을 포함한 사용자 토큰
+    pub async fn user_info(
+        &self,
+        access_token: String,
+        query: &user_info::RequestBody,
+    ) -> Result<user_info::ResponseBody, ApiError> {
+        self.call(
+            Endpoint::User(UserEndpoint::UserInfo),
+            user_info::METHOD,
+            Some(AuthorizationHeader::new(access_token)),
+            Some(query),
+        )
+        .await
+    }
+}
@@ -0,0 +1,163 @@
+use crate::types::datetime::Date;
+use crate::ApiError;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+/// 은행거래고유번호의 이용기관 부여번호(9자리) 최댓값
+pub const MAX_SEQUENCE: u64 = 999_999_999;
+
+/// 은행거래고유번호 이용기관 부여번호의 일련번호 저장소
+/// - 같은 이용기관이 여러 인스턴스에서 호출하거나 재시작해도 유일성을 유지하려면 DB/Redis 등 영속 저장소로 구현
+pub trait SequenceBackend: Send + Sync {
+    /// 해당 거래일자(KST, YYYYMMDD)의 다음 일련번호(1부터 시작)
+    fn next(&self, date: &str) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// 프로세스 내 atomic counter 기반 일련번호 저장소(거래일자가 바뀌면 1부터 다시 시작)
+/// - 상위 32비트에 거래일자, 하위 32비트에 일련번호를 담아 하나의 AtomicU64로 관리
+/// - 이미 다음 거래일자로 넘어간 뒤 이전 거래일자로 요청하면 일련번호가 중복되지 않도록 오류를 반환
+#[derive(Debug, Default)]
+pub struct AtomicSequence {
+    state: AtomicU64,
+}
+
+impl SequenceBackend for AtomicSequence {
+    fn next(&self, date: &str) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        let date: u64 = date.parse()?;
+        let mut current = self.state.load(Ordering::Acquire);
+        loop {
+            let seq = match date.cmp(&(current >> 32)) {
+                std::cmp::Ordering::Equal => (current & u32::MAX as u64) + 1,
+                std::cmp::Ordering::Greater => 1,
+                std::cmp::Ordering::Less => {
+                    return Err(format!(
+                        "date {} is older than the current sequence date {}",
+                        date,
+                        current >> 32
+                    )
+                    .into())
+                }
+            };
+            match self.state.compare_exchange_weak(
+                current,
+                date << 32 | seq,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return Ok(seq),
+                Err(actual) => current = actual,
+            }
+        }
+    }
+}
+
+/// 은행거래고유번호(이용기관코드 + "U" + 이용기관 부여번호 9자리) 생성기
+/// - institution_code: 이용기관코드(10자리)
+/// - backend: 일련번호 저장소(KST 기준 거래일자별로 1부터 다시 시작)
+#[derive(Clone)]
+pub struct BankTranIdGenerator {
+    institution_code: String,
+    backend: Arc<dyn SequenceBackend>,
+}
+
+impl std::fmt::Debug for BankTranIdGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BankTranIdGenerator")
+            .field("institution_code", &self.institution_code)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BankTranIdGenerator {
+    pub fn new(institution_code: String) -> Self {
+        Self::with_backend(institution_code, Arc::new(AtomicSequence::default()))
+    }
+
+    pub fn with_backend(institution_code: String, backend: Arc<dyn SequenceBackend>) -> Self {
+        Self {
+            institution_code,
+            backend,
+        }
+    }
+
+    pub fn institution_code(&self) -> &str {
+        &self.institution_code
+    }
+
+    /// 새 은행거래고유번호
+    pub fn next(&self) -> Result<String, ApiError> {
+        let date = Date::today().to_string();
+        let seq = self
+            .backend
+            .next(&date)
+            .map_err(ApiError::BankTranIdSequence)?;
+        if seq == 0 || seq > MAX_SEQUENCE {
+            return Err(ApiError::BankTranIdExhausted { date });
+        }
+        Ok(format!("{}U{:09}", self.institution_code, seq))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedSequence(u64);
+
+    impl SequenceBackend for FixedSequence {
+        fn next(&self, _date: &str) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(self.0)
+        }
+    }
+
+    #[test]
+    fn bank_tran_id_has_institution_code_and_nine_digit_sequence() {
+        let generator = BankTranIdGenerator::new("M202300001".to_string());
+        let bank_tran_id = generator.next().unwrap();
+        assert_eq!(bank_tran_id.len(), 20);
+        assert!(bank_tran_id.starts_with("M202300001U"));
+        assert!(bank_tran_id[11..].bytes().all(|b| b.is_ascii_digit()));
+        assert_ne!(generator.next().unwrap(), bank_tran_id);
+    }
+
+    #[test]
+    fn atomic_sequence_restarts_on_the_next_date() {
+        let sequence = AtomicSequence::default();
+        for seq in 1..=3 {
+            assert_eq!(sequence.next("20231231").unwrap(), seq);
+        }
+        assert_eq!(sequence.next("20240101").unwrap(), 1);
+        assert_eq!(sequence.next("20240102").unwrap(), 1);
+    }
+
+    #[test]
+    fn exhausted_sequence_is_an_error() {
+        let last = BankTranIdGenerator::with_backend(
+            "M202300001".to_string(),
+            Arc::new(FixedSequence(MAX_SEQUENCE)),
+        );
+        assert_eq!(last.next().unwrap(), "M202300001U999999999");
+        for seq in [0, MAX_SEQUENCE + 1] {
+            let generator = BankTranIdGenerator::with_backend(
+                "M202300001".to_string(),
+                Arc::new(FixedSequence(seq)),
+            );
+            assert!(matches!(
+                generator.next(),
+                Err(ApiError::BankTranIdExhausted { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn atomic_sequence_does_not_reset_for_an_older_date() {
+        let sequence = AtomicSequence::default();
+        assert_eq!(sequence.next("20231231").unwrap(), 1);
+        assert_eq!(sequence.next("20231231").unwrap(), 2);
+        assert_eq!(sequence.next("20240101").unwrap(), 1);
+        assert!(sequence.next("20231231").is_err());
+        assert_eq!(sequence.next("20240101").unwrap(), 2);
+    }
+}
@@ -0,0 +1,265 @@
+//! 동기(blocking) client
+//! - async client(api::oauth::OAuthApi)를 내부 current-thread Tokio runtime에서 실행
+//! - request/response body는 async client와 같은 types 모듈의 타입을 사용
+//! - async 함수 안(Tokio runtime 위)에서 호출하면 runtime을 막지 않도록 별도 thread에서 실행
+use crate::api::{
+    oauth::{OAuthApi as AsyncOAuthApi, OAuthApiBuilder},
+    pipeline::{ApiRequest, RawResponse},
+    rate_limit::RateLimiter,
+    transfer::{DepositBatchOutcome, TransferResolver},
+};
+use crate::config::ClientConfig;
+use crate::types::{
+    endpoint::Endpoint,
+    inquiry::{balance, real_name, receive, remit_list, transaction_list},
+    oauth::{revoke, token},
+    transfer::{deposit, result, withdraw},
+    user::user_info,
+    GetHash, HttpMethod,
+};
+use crate::ApiError;
+use serde::{de::DeserializeOwned, Serialize};
+use std::future::Future;
+use tokio::runtime::{Builder, Handle, Runtime};
+
+pub struct OAuthApi {
+    inner: AsyncOAuthApi,
+    runtime: Option<Runtime>,
+}
+
+impl OAuthApi {
+    pub fn new() -> Result<Self, ApiError> {
+        Self::from_async(AsyncOAuthApi::new())
+    }
+
+    /// async client의 builder로 설정한 client를 blocking client로 감쌈
+    pub fn build(builder: OAuthApiBuilder) -> Result<Self, ApiError> {
+        Self::from_async(builder.build()?)
+    }
+
+    pub fn from_async(inner: AsyncOAuthApi) -> Result<Self, ApiError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(ApiError::Runtime)?;
+        Ok(Self {
+            inner,
+            runtime: Some(runtime),
+        })
+    }
+
+    pub fn inner(&self) -> &AsyncOAuthApi {
+        &self.inner
+    }
+
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        self.inner.rate_limiter()
+    }
+
+    pub fn config(&self) -> Option<&ClientConfig> {
+        self.inner.config()
+    }
+
+    pub fn call<ReqHeader, ReqBody, ResBody>(
+        &self,
+        endpoint: Endpoint,
+        method: HttpMethod,
+        header: Option<ReqHeader>,
+        body: Option<ReqBody>,
+    ) -> Result<ResBody, ApiError>
+    where
+        ReqHeader: GetHash + Send,
+        ReqBody: Serialize + Send,
+        ResBody: DeserializeOwned + Send,
+    {
+        self.block_on(self.inner.call(endpoint, method, header, body))
+    }
+
+    pub fn execute(&self, request: ApiRequest) -> Result<RawResponse, ApiError> {
+        self.block_on(self.inner.execute(request))
+    }
+
+    /// 토큰발급(사용자 토큰: authorization_code, 이용기관 토큰: client_credentials)
+    pub fn token(&self, body: &token::RequestBody) -> Result<token::ResponseBody, ApiError> {
+        self.block_on(self.inner.token(body))
+    }
+
+    /// 토큰폐기
+    pub fn revoke(&self, body: &revoke::RequestBody) -> Result<revoke::ResponseBody, ApiError> {
+        self.block_on(self.inner.revoke(body))
+    }
+
+    /// 이용기관 설정으로 사용자 토큰(3-legged) 발급
+    pub fn issue_token(&self, code: String) -> Result<token::ResponseBody, ApiError> {
+        self.block_on(self.inner.issue_token(code))
+    }
+
+    /// 이용기관 설정으로 이용기관 토큰(2-legged) 발급
+    pub fn issue_oob_token(&self) -> Result<token::ResponseBody, ApiError> {
+        self.block_on(self.inner.issue_oob_token())
+    }
+
+    /// 이용기관 설정으로 토큰폐기
+    pub fn revoke_token(&self, access_token: String) -> Result<revoke::ResponseBody, ApiError> {
+        self.block_on(self.inner.revoke_token(access_token))
+    }
+
+    /// 사용자정보조회
+    /// - access_token: "login" scope을 포함한 사용자 토큰
+    pub fn user_info(
+        &self,
+        access_token: String,
+        query: &user_info::RequestBody,
+    ) -> Result<user_info::ResponseBody, ApiError> {
+        self.block_on(self.inner.user_info(access_token, query))
+    }
+
+    /// 잔액조회
+    /// - access_token: "inquiry" scope을 포함한 사용자 토큰
+    pub fn balance(
+        &self,
+        access_token: String,
+        query: &balance::RequestBody,
+    ) -> Result<balance::ResponseBody, ApiError> {
+        self.block_on(self.inner.balance(access_token, query))
+    }
+
+    /// 거래내역조회
+    /// - access_token: "inquiry" scope을 포함한 사용자 토큰
+    pub fn transaction_list(
+        &self,
+        access_token: String,
+        query: &transaction_list::RequestBody,
+    ) -> Result<transaction_list::ResponseBody, ApiError> {
+        self.block_on(self.inner.transaction_list(access_token, query))
+    }
+
+    /// 계좌실명조회
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub fn real_name(
+        &self,
+        access_token: String,
+        body: &real_name::RequestBody,
+    ) -> Result<real_name::ResponseBody, ApiError> {
+        self.block_on(self.inner.real_name(access_token, body))
+    }
+
+    /// 수취조회
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub fn receive(
+        &self,
+        access_token: String,
+        body: &receive::RequestBody,
+    ) -> Result<receive::ResponseBody, ApiError> {
+        self.block_on(self.inner.receive(access_token, body))
+    }
+
+    /// 수취조회 후 입금이체에 사용할 수취인 정보를 반환(수취조회가 성공하지 않은 경우 None)
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub fn verify_recipient(
+        &self,
+        access_token: String,
+        body: receive::RequestBody,
+    ) -> Result<Option<receive::VerifiedRecipient>, ApiError> {
+        self.block_on(self.inner.verify_recipient(access_token, body))
+    }
+
+    /// 송금인정보조회
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub fn remit_list(
+        &self,
+        access_token: String,
+        body: &remit_list::RequestBody,
+    ) -> Result<remit_list::ResponseBody, ApiError> {
+        self.block_on(self.inner.remit_list(access_token, body))
+    }
+
+    /// 출금이체(핀테크이용번호/계좌번호 사용)
+    /// - access_token: "transfer" scope을 포함한 사용자 토큰
+    pub fn withdraw(
+        &self,
+        access_token: String,
+        body: &withdraw::RequestBody,
+    ) -> Result<withdraw::ResponseBody, ApiError> {
+        self.block_on(self.inner.withdraw(access_token, body))
+    }
+
+    /// 입금이체(핀테크이용번호/계좌번호 사용)
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub fn deposit(
+        &self,
+        access_token: String,
+        body: &deposit::RequestBody,
+    ) -> Result<deposit::ResponseBody, ApiError> {
+        self.block_on(self.inner.deposit(access_token, body))
+    }
+
+    /// 입금이체 일괄처리(deposit::RequestBody::batches로 나눈 요청을 차례로 호출하고 건별 결과를 모음)
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub fn deposit_batch(
+        &self,
+        access_token: String,
+        bodies: Vec<deposit::RequestBody>,
+    ) -> DepositBatchOutcome {
+        self.block_on(self.inner.deposit_batch(access_token, bodies))
+    }
+
+    /// 이체결과조회
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    pub fn transfer_result(
+        &self,
+        access_token: String,
+        body: &result::RequestBody,
+    ) -> Result<result::ResponseBody, ApiError> {
+        self.block_on(self.inner.transfer_result(access_token, body))
+    }
+
+    /// 처리결과를 알 수 없는 이체의 결과가 확정될 때까지 이체결과조회를 반복
+    /// - access_token: "oob" scope을 포함한 이용기관 토큰
+    /// - check_type: 원거래의 종류(출금이체/입금이체)
+    /// - item: 원거래 정보
+    pub fn resolve_transfer(
+        &self,
+        access_token: String,
+        check_type: result::CheckType,
+        item: result::RequestItem,
+        resolver: &TransferResolver,
+    ) -> Result<result::ResponseItem, ApiError> {
+        self.block_on(
+            self.inner
+                .resolve_transfer(access_token, check_type, item, resolver),
+        )
+    }
+
+    /// 내부 runtime에서 future를 실행(Tokio runtime 위에서 호출되면 별도 thread에서 실행)
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send,
+        F::Output: Send,
+    {
+        let runtime = self
+            .runtime
+            .as_ref()
+            .expect("runtime is only taken on drop");
+        if Handle::try_current().is_err() {
+            return runtime.block_on(future);
+        }
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| runtime.block_on(future))
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e))
+        })
+    }
+}
+
+impl Drop for OAuthApi {
+    /// Tokio runtime 위에서 runtime을 drop하면 panic이 발생하므로 background에서 종료
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            if Handle::try_current().is_ok() {
+                runtime.shutdown_background();
+            }
+        }
+    }
+}
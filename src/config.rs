@@ -0,0 +1,193 @@
+//! 이용기관 설정
+//! - ClientConfig::from_env: 환경변수(OPEN_BANKING_*)에서 읽음
+//! - ClientConfig::from_toml_file: TOML 파일에서 읽음
+//! - OAuthApiBuilder::config로 넘기면 client가 토큰발급/토큰폐기 요청의 Client ID, Client Secret, Callback URL을 채우고 환경에 맞는 URL로 호출
+use crate::bank_tran_id::BankTranIdGenerator;
+use crate::types::{
+    endpoint::Environment,
+    oauth::{authorize, revoke, token},
+    secret::Secret,
+    AuthType, Scope,
+};
+use crate::ApiError;
+use serde::Deserialize;
+use std::{
+    path::Path,
+    sync::{Arc, OnceLock},
+};
+
+const ENV_CLIENT_ID: &str = "OPEN_BANKING_CLIENT_ID";
+const ENV_CLIENT_SECRET: &str = "OPEN_BANKING_CLIENT_SECRET";
+const ENV_INSTITUTION_CODE: &str = "OPEN_BANKING_INSTITUTION_CODE";
+const ENV_REDIRECT_URI: &str = "OPEN_BANKING_REDIRECT_URI";
+const ENV_ENVIRONMENT: &str = "OPEN_BANKING_ENVIRONMENT";
+
+/// 이용기관 설정
+/// - client_id: 오픈뱅킹에서 발급한 이용기관 앱의 Client ID
+/// - client_secret: 오픈뱅킹에서 발급한 이용기관 앱의 Client Secret
+/// - institution_code: 이용기관코드(10자리, 은행거래고유번호 앞자리)
+/// - redirect_uri: 사용자인증이 성공하면 이용기관으로 연결되는 Callback URL
+/// - environment: API 환경("test"/"prod", 미지정 시 Environment::default)
+///
+/// TOML 파일 예시
+/// ```toml
+/// client_id = "..."
+/// client_secret = "..."
+/// institution_code = "M202300001"
+/// redirect_uri = "https://example.com/callback"
+/// environment = "test"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClientConfig {
+    client_id: String,
+    client_secret: Secret,
+    institution_code: String,
+    redirect_uri: String,
+    #[serde(default)]
+    environment: Environment,
+    #[serde(skip)]
+    bank_tran_id: Arc<OnceLock<BankTranIdGenerator>>,
+}
+
+impl ClientConfig {
+    pub fn new(
+        client_id: String,
+        client_secret: String,
+        institution_code: String,
+        redirect_uri: String,
+        environment: Environment,
+    ) -> Self {
+        Self {
+            client_id,
+            client_secret: Secret::new(client_secret),
+            institution_code,
+            redirect_uri,
+            environment,
+            bank_tran_id: Arc::default(),
+        }
+    }
+
+    /// 환경변수에서 읽음
+    /// - OPEN_BANKING_CLIENT_ID, OPEN_BANKING_CLIENT_SECRET, OPEN_BANKING_INSTITUTION_CODE,
+    ///   OPEN_BANKING_REDIRECT_URI: 필수
+    /// - OPEN_BANKING_ENVIRONMENT: 선택("test"/"prod")
+    pub fn from_env() -> Result<Self, ApiError> {
+        let environment = match std::env::var(ENV_ENVIRONMENT) {
+            Ok(value) => value
+                .parse()
+                .map_err(|e| ApiError::Config(format!("{}: {}", ENV_ENVIRONMENT, e)))?,
+            Err(_) => Environment::default(),
+        };
+        Ok(Self::new(
+            required_env(ENV_CLIENT_ID)?,
+            required_env(ENV_CLIENT_SECRET)?,
+            required_env(ENV_INSTITUTION_CODE)?,
+            required_env(ENV_REDIRECT_URI)?,
+            environment,
+        ))
+    }
+
+    /// TOML 문자열에서 읽음
+    pub fn from_toml_str(toml: &str) -> Result<Self, ApiError> {
+        toml::from_str(toml).map_err(|e| ApiError::Config(e.to_string()))
+    }
+
+    /// TOML 파일에서 읽음
+    pub fn from_toml_file<P: AsRef<Path>>(path: P) -> Result<Self, ApiError> {
+        let path = path.as_ref();
+        let toml = std::fs::read_to_string(path)
+            .map_err(|e| ApiError::Config(format!("{}: {}", path.display(), e)))?;
+        Self::from_toml_str(&toml)
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn client_secret(&self) -> &Secret {
+        &self.client_secret
+    }
+
+    pub fn institution_code(&self) -> &str {
+        &self.institution_code
+    }
+
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    pub fn environment(&self) -> Environment {
+        self.environment
+    }
+
+    /// 이용기관코드로 만든 은행거래고유번호 생성기
+    /// - 처음 호출할 때 한 번만 만들고, 이후에는 같은 일련번호를 공유하는 생성기를 반환(복제한 ClientConfig도 공유)
+    /// - 같은 이용기관코드로 따로 읽은 ClientConfig끼리는 일련번호를 공유하지 않으므로 이용기관별로 설정을 한 번만 읽어 사용
+    pub fn bank_tran_id_generator(&self) -> BankTranIdGenerator {
+        self.bank_tran_id
+            .get_or_init(|| BankTranIdGenerator::new(self.institution_code.clone()))
+            .clone()
+    }
+
+    /// 사용자인증 API request body(선택 항목은 미지정)
+    pub fn authorize_request(
+        &self,
+        scope: Vec<Scope>,
+        state: Vec<u8>,
+        auth_type: AuthType,
+    ) -> authorize::RequestBody {
+        authorize::RequestBody::new_authorize(
+            self.client_id.clone(),
+            self.redirect_uri.clone(),
+            scope,
+            None,
+            state,
+            auth_type,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// 사용자 토큰(3-legged) 발급 request body
+    pub fn token_request(&self, code: String) -> token::RequestBody {
+        token::RequestBody::new(
+            code,
+            self.client_id.clone(),
+            self.client_secret.expose().to_string(),
+            self.redirect_uri.clone(),
+        )
+    }
+
+    /// 이용기관 토큰(2-legged) 발급 request body
+    pub fn client_credentials_request(&self) -> token::RequestBody {
+        token::RequestBody::new_client_credentials(
+            self.client_id.clone(),
+            self.client_secret.expose().to_string(),
+        )
+    }
+
+    /// 토큰폐기 request body
+    pub fn revoke_request(&self, access_token: String) -> revoke::RequestBody {
+        revoke::RequestBody::new(
+            self.client_id.clone(),
+            self.client_secret.expose().to_string(),
+            access_token,
+        )
+    }
+}
+
+fn required_env(name: &str) -> Result<String, ApiError> {
+    std::env::var(name).map_err(|e| ApiError::Config(format!("{}: {}", name, e)))
+}
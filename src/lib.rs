@@ -1,5 +1,15 @@
 pub mod api;
-pub(crate) mod types;
+pub mod bank_tran_id;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod config;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod tenant;
+pub mod types;
+
+use types::endpoint::EndpointGroup;
+use types::response_code::{BankResponseCode, ResponseCode, ResponseCodeClass};
 
 #[derive(thiserror::Error, Debug)]
 pub enum ApiError {
@@ -8,5 +18,103 @@ pub enum ApiError {
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
     #[error(transparent)]
+    UrlEncodedError(#[from] serde_urlencoded::ser::Error),
+    #[error(transparent)]
     InvalidReqwestHeaderValue(#[from] reqwest::header::InvalidHeaderValue),
+    #[error("HTTP transport failed: {0}")]
+    Transport(#[source] tower::BoxError),
+    #[error("transfer {org_bank_tran_id} is still unresolved after {attempts} result inquiries")]
+    TransferUnresolved {
+        org_bank_tran_id: String,
+        attempts: u32,
+    },
+    #[error("open banking API responded with HTTP {status}: {body}")]
+    Http {
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+    },
+    #[error("failed to decode open banking API response (HTTP {status}): {source}")]
+    Decode {
+        #[source]
+        source: serde_json::Error,
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+    },
+    #[error("open banking API responded with {rsp_code}: {rsp_message}")]
+    OpenBanking {
+        rsp_code: ResponseCode,
+        rsp_message: String,
+        bank_rsp_code: Option<BankResponseCode>,
+        api_tran_id: Option<String>,
+        raw: api::pipeline::RawBody,
+    },
+    #[error("request to {group:?} endpoints was throttled by the client-side rate limiter")]
+    RateLimited { group: EndpointGroup },
+    #[error("failed to get bank_tran_id sequence: {0}")]
+    BankTranIdSequence(Box<dyn std::error::Error + Send + Sync>),
+    #[error("bank_tran_id sequence for {date} is exhausted")]
+    BankTranIdExhausted { date: String },
+    #[error("invalid client config: {0}")]
+    Config(String),
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("tenant {key:?} is not registered")]
+    UnknownTenant { key: String },
+    #[error("token store failed: {0}")]
+    TokenStore(Box<dyn std::error::Error + Send + Sync>),
+    #[cfg(feature = "blocking")]
+    #[error("failed to start the blocking client runtime: {0}")]
+    Runtime(std::io::Error),
+    #[cfg(feature = "cassette")]
+    #[error("cassette {path:?}: {reason}")]
+    Cassette {
+        path: std::path::PathBuf,
+        reason: String,
+    },
+}
+
+impl ApiError {
+    /// 잠시 후 같은 요청을 다시 보내면 성공할 수 있는 일시적인 오류인지 여부
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::ReqwestError(e) => e.is_connect() || e.is_timeout(),
+            Self::Transport(e) => e
+                .downcast_ref::<std::io::Error>()
+                .is_some_and(|e| e.kind() == std::io::ErrorKind::TimedOut),
+            Self::Http { status, .. } => {
+                status.is_server_error() || *status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            Self::OpenBanking { rsp_code, .. } => rsp_code.class() == ResponseCodeClass::Retryable,
+            _ => false,
+        }
+    }
+
+    /// 요청이 KFTC에 전달되었을 수 있어 이체 처리결과를 알 수 없는 오류인지 여부
+    pub fn is_outcome_unknown(&self) -> bool {
+        match self {
+            Self::ReqwestError(e) => !e.is_connect() && !e.is_builder(),
+            Self::SerdeJsonError(_) | Self::UrlEncodedError(_) => false,
+            Self::Http { status, .. } => status.is_server_error(),
+            Self::Decode { .. } => true,
+            Self::InvalidReqwestHeaderValue(_) => false,
+            // 사용자 transport의 오류는 요청 전송 여부를 알 수 없음
+            Self::Transport(_) => true,
+            Self::TransferUnresolved { .. } => true,
+            Self::OpenBanking {
+                rsp_code,
+                bank_rsp_code,
+                ..
+            } => types::transfer::is_outcome_unknown(rsp_code, bank_rsp_code.as_ref()),
+            Self::RateLimited { .. } => false,
+            Self::BankTranIdSequence(_) | Self::BankTranIdExhausted { .. } => false,
+            Self::Config(_) | Self::InvalidRequest(_) => false,
+            Self::UnknownTenant { .. } | Self::TokenStore(_) => false,
+            #[cfg(feature = "blocking")]
+            Self::Runtime(_) => false,
+            #[cfg(feature = "cassette")]
+            Self::Cassette { .. } => false,
+        }
+    }
 }
@@ -0,0 +1,357 @@
+//! 오픈뱅킹 API mock(`mock` feature)
+//! - 이용기관/사용자/계좌/잔액을 메모리에 두고 OAuth, 사용자, 조회, 이체 API를 흉내내는 tower service
+//! - 출금이체/입금이체는 계좌 잔액을 바꾸고 거래내역을 남기며, 출금한도와 조회/출금 동의 여부를 확인
+//! - MockKftc::transport로 OAuthApi에 연결하면 네트워크와 등록된 인증정보 없이 호출 가능
+//! - 사용자인증(authorize)은 브라우저 화면이므로 MockKftc::authorization_code로 인가코드를 대신 발급
+//! - MockKftc::inject로 토큰 만료, 응답코드, 타임아웃 등의 장애를 주입
+mod routes;
+
+use crate::api::{
+    pipeline::BoxFuture,
+    transport::{HttpRequest, HttpResponse, Transport},
+};
+use crate::types::{
+    endpoint::{Endpoint, InquiryEndpoint, OAuthEndpoint, TransferEndpoint, UserEndpoint},
+    response_code::ResponseCode,
+};
+use reqwest::StatusCode;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll},
+    time::Duration,
+};
+use tower::{BoxError, Service};
+
+/// mock이 처리하는 API 목록
+const ENDPOINTS: [Endpoint; 24] = [
+    Endpoint::OAuth(OAuthEndpoint::Authorize),
+    Endpoint::OAuth(OAuthEndpoint::Token),
+    Endpoint::OAuth(OAuthEndpoint::Revoke),
+    Endpoint::OAuth(OAuthEndpoint::AuthorizeAccount),
+    Endpoint::User(UserEndpoint::UserInfo),
+    Endpoint::User(UserEndpoint::Unlink),
+    Endpoint::User(UserEndpoint::ListAccount),
+    Endpoint::User(UserEndpoint::UpdateAccount),
+    Endpoint::User(UserEndpoint::CancelAccount),
+    Endpoint::User(UserEndpoint::CancelCard),
+    Endpoint::User(UserEndpoint::CancelPays),
+    Endpoint::User(UserEndpoint::CancelInsurances),
+    Endpoint::User(UserEndpoint::CancelLoans),
+    Endpoint::User(UserEndpoint::Quit),
+    Endpoint::Inquiry(InquiryEndpoint::Balance),
+    Endpoint::Inquiry(InquiryEndpoint::ListTransaction),
+    Endpoint::Inquiry(InquiryEndpoint::RealName),
+    Endpoint::Inquiry(InquiryEndpoint::RemitList),
+    Endpoint::Inquiry(InquiryEndpoint::Receive),
+    Endpoint::Transfer(TransferEndpoint::WithdrawFinNum),
+    Endpoint::Transfer(TransferEndpoint::WithdrawAcntNum),
+    Endpoint::Transfer(TransferEndpoint::DepositFinNum),
+    Endpoint::Transfer(TransferEndpoint::DepositAcntNum),
+    Endpoint::Transfer(TransferEndpoint::Result),
+];
+
+/// mock에 주입할 장애
+#[derive(Debug, Clone)]
+pub enum Fault {
+    ExpiredToken,         // Access Token 만료(O0003)로 응답(요청은 처리되지 않음)
+    Reject(ResponseCode), // 지정한 응답코드로 응답(요청은 처리되지 않음)
+    Status(StatusCode),   // 지정한 HTTP 상태코드로 응답(요청은 처리되지 않음)
+    BankTimeout,          // 요청을 처리한 뒤 참가기관 응답 지연(A0007)으로 응답
+    Timeout,              // 요청을 처리한 뒤 응답 대신 transport 타임아웃 오류를 반환
+    Delay(Duration),      // 지정한 시간만큼 지연한 뒤 정상 처리
+}
+
+/// mock 계좌
+/// - fintech_use_num: 핀테크이용번호
+/// - bank_code_std: 개설기관.표준코드
+/// - account_num: 계좌번호
+/// - account_holder_name: 예금주성명
+/// - balance: 잔액
+/// - user_seq_no: 계좌를 등록한 사용자일련번호(이용기관 약정계좌는 None)
+/// - inquiry_agreed: 조회서비스 동의여부(잔액조회/거래내역조회)
+/// - transfer_agreed: 출금서비스 동의여부(출금이체)
+/// - daily_withdraw_limit: 출금이체 일 한도(None이면 한도 없음)
+#[derive(Debug, Clone)]
+pub struct MockAccount {
+    fintech_use_num: String,
+    bank_code_std: String,
+    account_num: String,
+    account_holder_name: String,
+    balance: i64,
+    user_seq_no: Option<String>,
+    inquiry_agreed: bool,
+    transfer_agreed: bool,
+    daily_withdraw_limit: Option<i64>,
+}
+
+impl MockAccount {
+    pub fn new(
+        fintech_use_num: String,
+        bank_code_std: String,
+        account_num: String,
+        account_holder_name: String,
+        balance: i64,
+    ) -> Self {
+        Self {
+            fintech_use_num,
+            bank_code_std,
+            account_num,
+            account_holder_name,
+            balance,
+            user_seq_no: None,
+            inquiry_agreed: true,
+            transfer_agreed: true,
+            daily_withdraw_limit: None,
+        }
+    }
+
+    /// 계좌를 등록한 사용자 지정
+    pub fn owned_by(mut self, user_seq_no: String) -> Self {
+        self.user_seq_no = Some(user_seq_no);
+        self
+    }
+
+    /// 계좌 등록 시의 조회/출금서비스 동의여부(기본값: 모두 동의)
+    pub fn with_agreements(mut self, inquiry_agreed: bool, transfer_agreed: bool) -> Self {
+        self.inquiry_agreed = inquiry_agreed;
+        self.transfer_agreed = transfer_agreed;
+        self
+    }
+
+    /// 출금이체 일 한도
+    pub fn with_daily_withdraw_limit(mut self, limit: i64) -> Self {
+        self.daily_withdraw_limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MockUser {
+    user_name: String,
+    user_ci: String,
+}
+
+#[derive(Debug, Clone)]
+struct MockToken {
+    scope: String,
+    user_seq_no: Option<String>,
+    expired: bool,
+}
+
+/// 이체결과조회/송금인정보조회에 사용할 이체 기록
+#[derive(Debug, Clone)]
+struct TransferRecord {
+    check_type: &'static str,
+    bank_tran_date: String,
+    tran_time: String,
+    tran_amt: i64,
+    bank_rsp_code: &'static str,
+    bank_rsp_message: &'static str,
+    cntr_account_num: String,
+    account: MockAccount,
+    print_content: String,
+}
+
+/// 거래내역조회에 사용할 계좌별 거래내역
+#[derive(Debug, Clone)]
+struct LedgerEntry {
+    fintech_use_num: String,
+    tran_date: String,
+    tran_time: String,
+    deposit: bool,
+    print_content: String,
+    tran_amt: i64,
+    after_balance_amt: i64,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    clients: HashMap<String, String>,
+    users: HashMap<String, MockUser>,
+    accounts: Vec<MockAccount>,
+    codes: HashMap<String, String>,
+    tokens: HashMap<String, MockToken>,
+    transfers: HashMap<String, TransferRecord>,
+    ledger: Vec<LedgerEntry>,
+    faults: VecDeque<(Option<Endpoint>, Fault)>,
+    calls: HashMap<Endpoint, usize>,
+    seq: u64,
+}
+
+impl State {
+    fn next_seq(&mut self) -> u64 {
+        self.seq += 1;
+        self.seq
+    }
+
+    fn take_fault(&mut self, endpoint: Endpoint) -> Option<Fault> {
+        let index = self
+            .faults
+            .iter()
+            .position(|(target, _)| target.is_none() || *target == Some(endpoint))?;
+        self.faults.remove(index).map(|(_, fault)| fault)
+    }
+}
+
+/// in-memory 오픈뱅킹 API mock(복제한 MockKftc는 같은 상태를 공유)
+#[derive(Debug, Clone, Default)]
+pub struct MockKftc {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockKftc {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// OAuthApi에 연결할 transport
+    pub fn transport(&self) -> Transport {
+        Transport::new(self.clone())
+    }
+
+    /// 이용기관 앱 등록
+    pub fn add_client(&self, client_id: String, client_secret: String) {
+        self.lock().clients.insert(client_id, client_secret);
+    }
+
+    /// 사용자 등록
+    pub fn add_user(&self, user_seq_no: String, user_name: String, user_ci: String) {
+        self.lock()
+            .users
+            .insert(user_seq_no, MockUser { user_name, user_ci });
+    }
+
+    /// 계좌 등록(이용기관 약정계좌도 계좌로 등록하면 입출금이 잔액에 반영됨)
+    pub fn add_account(&self, account: MockAccount) {
+        self.lock().accounts.push(account);
+    }
+
+    /// 사용자인증을 마친 것으로 보고 토큰발급에 사용할 인가코드를 발급
+    pub fn authorization_code(&self, user_seq_no: &str) -> String {
+        let mut state = self.lock();
+        let code = format!("mock-code-{}", state.next_seq());
+        state.codes.insert(code.clone(), user_seq_no.to_string());
+        code
+    }
+
+    /// 토큰발급 API를 거치지 않고 Access Token을 발급
+    /// - scope: 공백으로 구분한 권한 범위(예: "oob", "login inquiry transfer")
+    /// - user_seq_no: 사용자 토큰인 경우 사용자일련번호
+    pub fn issue_token(&self, scope: &str, user_seq_no: Option<&str>) -> String {
+        self.lock()
+            .issue_token(scope.to_string(), user_seq_no.map(str::to_string))
+    }
+
+    /// Access Token을 만료시킴
+    pub fn expire_token(&self, access_token: &str) {
+        if let Some(token) = self.lock().tokens.get_mut(access_token) {
+            token.expired = true;
+        }
+    }
+
+    /// 핀테크이용번호에 해당하는 계좌의 잔액
+    pub fn balance(&self, fintech_use_num: &str) -> Option<i64> {
+        self.lock()
+            .accounts
+            .iter()
+            .find(|account| account.fintech_use_num == fintech_use_num)
+            .map(|account| account.balance)
+    }
+
+    /// 계좌의 조회/출금서비스 동의여부 변경(사용자가 동의를 철회한 경우 등)
+    pub fn set_agreements(
+        &self,
+        fintech_use_num: &str,
+        inquiry_agreed: bool,
+        transfer_agreed: bool,
+    ) {
+        for account in self
+            .lock()
+            .accounts
+            .iter_mut()
+            .filter(|account| account.fintech_use_num == fintech_use_num)
+        {
+            account.inquiry_agreed = inquiry_agreed;
+            account.transfer_agreed = transfer_agreed;
+        }
+    }
+
+    /// 다음 요청(API 무관)에 장애를 주입(여러 번 주입하면 차례로 적용됨)
+    pub fn inject(&self, fault: Fault) {
+        self.lock().faults.push_back((None, fault));
+    }
+
+    /// 지정한 API의 다음 요청에 장애를 주입
+    pub fn inject_on(&self, endpoint: Endpoint, fault: Fault) {
+        self.lock().faults.push_back((Some(endpoint), fault));
+    }
+
+    /// API가 호출된 횟수(재시도 포함)
+    pub fn calls(&self, endpoint: Endpoint) -> usize {
+        self.lock()
+            .calls
+            .get(&endpoint)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Service<HttpRequest> for MockKftc {
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<HttpResponse, BoxError>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let mock = self.clone();
+        Box::pin(async move {
+            // 테스트/운영 환경 모두 같은 mock으로 처리
+            let path = request.uri().path().to_string();
+            let Some(endpoint) = ENDPOINTS
+                .into_iter()
+                .find(|endpoint| endpoint.path() == path)
+            else {
+                return Ok(routes::Reply::not_found().into_response());
+            };
+            let fault = {
+                let mut state = mock.lock();
+                *state.calls.entry(endpoint).or_default() += 1;
+                state.take_fault(endpoint)
+            };
+            if let Some(Fault::Delay(delay)) = fault {
+                tokio::time::sleep(delay).await;
+            }
+
+            let mut state = mock.lock();
+            let reply = match fault {
+                Some(Fault::ExpiredToken) => {
+                    state.error(ResponseCode::AccessTokenExpired, "Access Token 만료")
+                }
+                Some(Fault::Reject(rsp_code)) => state.error(rsp_code, "mock 장애 주입"),
+                Some(Fault::Status(status)) => routes::Reply::status(status),
+                Some(Fault::BankTimeout) => {
+                    state.handle(endpoint, &request);
+                    state.error(ResponseCode::BankTimeout, "참가기관 응답 지연")
+                }
+                Some(Fault::Timeout) => {
+                    state.handle(endpoint, &request);
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        "mock open banking API timed out",
+                    )
+                    .into());
+                }
+                Some(Fault::Delay(_)) | None => state.handle(endpoint, &request),
+            };
+            Ok(reply.into_response())
+        })
+    }
+}
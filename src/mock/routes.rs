@@ -0,0 +1,759 @@
+use super::{LedgerEntry, MockToken, State, TransferRecord};
+use crate::api::transport::{HttpRequest, HttpResponse};
+use crate::types::{
+    datetime::{now_kst, ApiTranDtm, TranDtime},
+    endpoint::{Endpoint, InquiryEndpoint, OAuthEndpoint, TransferEndpoint, UserEndpoint},
+    response_code::ResponseCode,
+    secret::mask_account_num,
+};
+use reqwest::{header::CONTENT_TYPE, StatusCode};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+/// 참가기관이 거래를 거절한 경우의 응답코드(참가기관)(mock 전용)
+const BANK_RSP_REJECTED: &str = "999";
+const BANK_RSP_SUCCESS: &str = "000";
+/// 토큰발급 시 돌려주는 만료 기간(90일)
+const TOKEN_EXPIRES_IN: u64 = 90 * 24 * 3600;
+/// 사용자인증으로 발급한 사용자 토큰의 권한 범위
+const USER_SCOPE: &str = "login inquiry transfer";
+/// 거래내역조회 한 페이지의 최대 건수
+const TRANSACTION_PAGE_SIZE: usize = 25;
+
+/// mock 응답
+pub(super) struct Reply {
+    status: StatusCode,
+    body: Value,
+}
+
+impl Reply {
+    fn ok(body: Value) -> Self {
+        Self {
+            status: StatusCode::OK,
+            body,
+        }
+    }
+
+    pub(super) fn status(status: StatusCode) -> Self {
+        Self {
+            status,
+            body: json!({ "rsp_code": "", "rsp_message": status.to_string() }),
+        }
+    }
+
+    pub(super) fn not_found() -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            body: json!({
+                "rsp_code": ResponseCode::NotFound.code(),
+                "rsp_message": ResponseCode::NotFound.description_ko(),
+            }),
+        }
+    }
+
+    pub(super) fn into_response(self) -> HttpResponse {
+        http::Response::builder()
+            .status(self.status)
+            .header(CONTENT_TYPE, "application/json; charset=UTF-8")
+            .body(self.body.to_string())
+            .expect("mock response is valid")
+    }
+}
+
+/// 요청 파라미터(query, form, JSON 본문)
+struct Params(Value);
+
+impl Params {
+    fn parse(endpoint: Endpoint, request: &HttpRequest) -> Self {
+        let encoded = match endpoint {
+            Endpoint::OAuth(OAuthEndpoint::Token | OAuthEndpoint::Revoke) => {
+                Some(request.body().as_str())
+            }
+            _ if request.method() == http::Method::GET => request.uri().query(),
+            _ => return Self(serde_json::from_str(request.body()).unwrap_or(Value::Null)),
+        };
+        let pairs: HashMap<String, String> =
+            serde_urlencoded::from_str(encoded.unwrap_or_default()).unwrap_or_default();
+        Self(Value::Object(
+            pairs
+                .into_iter()
+                .map(|(k, v)| (k, Value::String(v)))
+                .collect(),
+        ))
+    }
+
+    fn get(&self, name: &str) -> &str {
+        self.0.get(name).and_then(Value::as_str).unwrap_or_default()
+    }
+
+    fn amount(&self, name: &str) -> Option<i64> {
+        self.get(name).parse().ok().filter(|amount| *amount > 0)
+    }
+
+    fn list(&self, name: &str) -> Vec<Params> {
+        match self.0.get(name) {
+            Some(Value::Array(items)) => items.iter().cloned().map(Params).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl State {
+    pub(super) fn issue_token(&mut self, scope: String, user_seq_no: Option<String>) -> String {
+        let access_token = format!("mock-access-token-{}", self.next_seq());
+        self.tokens.insert(
+            access_token.clone(),
+            MockToken {
+                scope,
+                user_seq_no,
+                expired: false,
+            },
+        );
+        access_token
+    }
+
+    /// 거래고유번호(API)와 거래일시가 포함된 응답 본문
+    fn envelope(&mut self, rsp_code: ResponseCode, rsp_message: &str) -> Map<String, Value> {
+        let mut body = Map::new();
+        body.insert(
+            "api_tran_id".to_string(),
+            json!(format!("MOCK{:016}", self.next_seq())),
+        );
+        body.insert(
+            "api_tran_dtm".to_string(),
+            json!(ApiTranDtm::now().to_string()),
+        );
+        body.insert("rsp_code".to_string(), json!(rsp_code.code()));
+        body.insert("rsp_message".to_string(), json!(rsp_message));
+        body
+    }
+
+    fn success(&mut self, fields: Value) -> Reply {
+        self.reply(ResponseCode::ApiSuccess, "", fields)
+    }
+
+    fn reply(&mut self, rsp_code: ResponseCode, rsp_message: &str, fields: Value) -> Reply {
+        let mut body = self.envelope(rsp_code, rsp_message);
+        if let Value::Object(fields) = fields {
+            body.extend(fields);
+        }
+        Reply::ok(Value::Object(body))
+    }
+
+    pub(super) fn error(&mut self, rsp_code: ResponseCode, rsp_message: &str) -> Reply {
+        Reply::ok(Value::Object(self.envelope(rsp_code, rsp_message)))
+    }
+
+    fn bank_error(&mut self, bank_rsp_message: &str) -> Reply {
+        let mut body = self.envelope(ResponseCode::BankError, "참가기관 오류");
+        body.insert("bank_rsp_code".to_string(), json!(BANK_RSP_REJECTED));
+        body.insert("bank_rsp_message".to_string(), json!(bank_rsp_message));
+        Reply::ok(Value::Object(body))
+    }
+
+    /// Authorization 헤더의 Access Token이 유효하고 scope을 포함하는지 확인
+    fn authorize(&mut self, request: &HttpRequest, scope: &str) -> Result<MockToken, Reply> {
+        let token = request
+            .headers()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|access_token| self.tokens.get(access_token))
+            .cloned();
+        match token {
+            None => Err(self.error(ResponseCode::AccessTokenDenied, "Access Token 거부")),
+            Some(token) if token.expired => {
+                Err(self.error(ResponseCode::AccessTokenExpired, "Access Token 만료"))
+            }
+            Some(token) if !token.scope.split(' ').any(|s| s == scope) => {
+                Err(self.error(ResponseCode::WrongScope, "허용되지 않은 scope"))
+            }
+            Some(token) => Ok(token),
+        }
+    }
+
+    fn find_account(&self, params: &Params) -> Option<usize> {
+        let fintech_use_num = params.get("fintech_use_num");
+        self.accounts.iter().position(|account| {
+            if fintech_use_num.is_empty() {
+                account.bank_code_std == params.get("bank_code_std")
+                    && account.account_num == params.get("account_num")
+            } else {
+                account.fintech_use_num == fintech_use_num
+            }
+        })
+    }
+
+    fn find_cntr_account(&self, params: &Params) -> Option<usize> {
+        let cntr_account_num = params.get("cntr_account_num");
+        self.accounts
+            .iter()
+            .position(|account| account.account_num == cntr_account_num)
+    }
+
+    fn user_accounts(&self, user_seq_no: &str) -> Vec<Value> {
+        let agree_dtime = |agreed: bool| match agreed {
+            true => TranDtime::now().to_string(),
+            false => String::new(),
+        };
+        self.accounts
+            .iter()
+            .filter(|account| account.user_seq_no.as_deref() == Some(user_seq_no))
+            .map(|account| {
+                json!({
+                    "fintech_use_num": account.fintech_use_num,
+                    "bank_code_std": account.bank_code_std,
+                    "account_num_masked": mask_account_num(&account.account_num),
+                    "account_holder_name": account.account_holder_name,
+                    "account_holder_type": "P",
+                    "inquiry_agree_yn": if account.inquiry_agreed { "Y" } else { "N" },
+                    "inquiry_agree_dtime": agree_dtime(account.inquiry_agreed),
+                    "transfer_agree_yn": if account.transfer_agreed { "Y" } else { "N" },
+                    "transfer_agree_dtime": agree_dtime(account.transfer_agreed),
+                    "account_state": "01",
+                })
+            })
+            .collect()
+    }
+
+    pub(super) fn handle(&mut self, endpoint: Endpoint, request: &HttpRequest) -> Reply {
+        let params = Params::parse(endpoint, request);
+        let result = match endpoint {
+            Endpoint::OAuth(OAuthEndpoint::Token) => Ok(self.token(&params)),
+            Endpoint::OAuth(OAuthEndpoint::Revoke) => Ok(self.revoke(&params)),
+            Endpoint::OAuth(OAuthEndpoint::Authorize | OAuthEndpoint::AuthorizeAccount) => {
+                Ok(Reply::not_found())
+            }
+            Endpoint::User(endpoint) => self.user(endpoint, request, &params),
+            Endpoint::Inquiry(InquiryEndpoint::Balance | InquiryEndpoint::ListTransaction) => self
+                .authorize(request, "inquiry")
+                .map(|token| self.account_inquiry(endpoint, &token, &params)),
+            Endpoint::Inquiry(endpoint) => self
+                .authorize(request, "oob")
+                .map(|_| self.oob_inquiry(endpoint, &params)),
+            Endpoint::Transfer(
+                TransferEndpoint::WithdrawFinNum | TransferEndpoint::WithdrawAcntNum,
+            ) => self
+                .authorize(request, "transfer")
+                .map(|token| self.withdraw(&token, &params)),
+            Endpoint::Transfer(
+                TransferEndpoint::DepositFinNum | TransferEndpoint::DepositAcntNum,
+            ) => self
+                .authorize(request, "oob")
+                .map(|_| self.deposit(&params)),
+            Endpoint::Transfer(TransferEndpoint::Result) => self
+                .authorize(request, "oob")
+                .map(|_| self.transfer_result(&params)),
+        };
+        result.unwrap_or_else(|reply| reply)
+    }
+
+    fn token(&mut self, params: &Params) -> Reply {
+        if self
+            .clients
+            .get(params.get("client_id"))
+            .map(String::as_str)
+            != Some(params.get("client_secret"))
+        {
+            return self.error(ResponseCode::ParameterError, "client_id/client_secret 오류");
+        }
+        let (scope, user_seq_no) = match params.get("grant_type") {
+            "authorization_code" => match self.codes.remove(params.get("code")) {
+                Some(user_seq_no) => (USER_SCOPE.to_string(), Some(user_seq_no)),
+                None => return self.error(ResponseCode::ParameterError, "인가코드 오류"),
+            },
+            "client_credentials" if params.get("scope") == "oob" => ("oob".to_string(), None),
+            _ => return self.error(ResponseCode::ParameterError, "grant_type 오류"),
+        };
+        let access_token = self.issue_token(scope.clone(), user_seq_no.clone());
+        let refresh_token = format!("mock-refresh-token-{}", self.next_seq());
+        Reply::ok(json!({
+            "access_token": access_token,
+            "token_type": "Bearer",
+            "expires_in": TOKEN_EXPIRES_IN,
+            "refresh_token": refresh_token,
+            "scope": scope,
+            "user_seq_no": user_seq_no,
+        }))
+    }
+
+    fn revoke(&mut self, params: &Params) -> Reply {
+        if self
+            .clients
+            .get(params.get("client_id"))
+            .map(String::as_str)
+            != Some(params.get("client_secret"))
+        {
+            return self.error(ResponseCode::ParameterError, "client_id/client_secret 오류");
+        }
+        self.tokens.remove(params.get("access_token"));
+        Reply::ok(json!({
+            "rsp_code": ResponseCode::Success.code(),
+            "rsp_message": "",
+            "client_id": params.get("client_id"),
+            "access_token": params.get("access_token"),
+        }))
+    }
+
+    fn user(
+        &mut self,
+        endpoint: UserEndpoint,
+        request: &HttpRequest,
+        params: &Params,
+    ) -> Result<Reply, Reply> {
+        let token = self.authorize(request, "login")?;
+        let user_seq_no = token.user_seq_no.unwrap_or_default();
+        let Some(user) = self.users.get(&user_seq_no).cloned() else {
+            return Err(self.error(ResponseCode::AccessTokenDenied, "사용자 토큰이 아님"));
+        };
+        Ok(match endpoint {
+            UserEndpoint::UserInfo | UserEndpoint::ListAccount => {
+                if params.get("user_seq_no") != user_seq_no {
+                    return Err(self.error(ResponseCode::RequestFormatError, "사용자일련번호 오류"));
+                }
+                let res_list = self.user_accounts(&user_seq_no);
+                self.success(json!({
+                    "user_seq_no": user_seq_no,
+                    "user_ci": user.user_ci,
+                    "user_name": user.user_name,
+                    "res_cnt": res_list.len().to_string(),
+                    "res_list": res_list,
+                }))
+            }
+            UserEndpoint::CancelAccount => {
+                let fintech_use_num = params.get("fintech_use_num");
+                for account in self.accounts.iter_mut().filter(|account| {
+                    account.fintech_use_num == fintech_use_num
+                        && account.user_seq_no.as_deref() == Some(user_seq_no.as_str())
+                }) {
+                    account.user_seq_no = None;
+                }
+                self.success(json!({ "fintech_use_num": fintech_use_num }))
+            }
+            UserEndpoint::Unlink | UserEndpoint::Quit => {
+                self.tokens
+                    .retain(|_, token| token.user_seq_no.as_deref() != Some(user_seq_no.as_str()));
+                if endpoint == UserEndpoint::Quit {
+                    self.users.remove(&user_seq_no);
+                    for account in self.accounts.iter_mut().filter(|account| {
+                        account.user_seq_no.as_deref() == Some(user_seq_no.as_str())
+                    }) {
+                        account.user_seq_no = None;
+                    }
+                }
+                self.success(json!({ "user_seq_no": user_seq_no }))
+            }
+            _ => self.success(json!({})),
+        })
+    }
+
+    fn account_inquiry(&mut self, endpoint: Endpoint, token: &MockToken, params: &Params) -> Reply {
+        let account = self
+            .find_account(params)
+            .map(|index| self.accounts[index].clone())
+            .filter(|account| account.user_seq_no == token.user_seq_no);
+        let Some(account) = account else {
+            return self.bank_error("등록되지 않은 계좌");
+        };
+        if !account.inquiry_agreed {
+            return self.error(ResponseCode::AccessDenied, "조회서비스 동의 없음");
+        }
+        let today = now_kst().format("%Y%m%d").to_string();
+        let mut fields = json!({
+            "bank_tran_id": params.get("bank_tran_id"),
+            "bank_tran_date": today,
+            "bank_code_tran": account.bank_code_std,
+            "bank_rsp_code": BANK_RSP_SUCCESS,
+            "bank_rsp_message": "",
+            "fintech_use_num": account.fintech_use_num,
+            "balance_amt": account.balance.to_string(),
+        });
+        if endpoint == Endpoint::Inquiry(InquiryEndpoint::Balance) {
+            fields["available_amt"] = json!(account.balance.max(0).to_string());
+            fields["account_type"] = json!("1");
+            fields["product_name"] = json!("mock 입출금통장");
+        } else {
+            let (res_list, next_offset) = self.transactions(&account.fintech_use_num, params);
+            fields["page_record_cnt"] = json!(res_list.len().to_string());
+            fields["next_page_yn"] = json!(if next_offset.is_some() { "Y" } else { "N" });
+            fields["befor_inquiry_trace_info"] = json!(next_offset
+                .map(|offset| offset.to_string())
+                .unwrap_or_default());
+            fields["res_list"] = json!(res_list);
+        }
+        self.success(fields)
+    }
+
+    /// 조회 조건에 맞는 거래내역 한 페이지와 다음 페이지의 시작 위치
+    fn transactions(&self, fintech_use_num: &str, params: &Params) -> (Vec<Value>, Option<usize>) {
+        let by_time = params.get("inquiry_base") == "T";
+        let from = format!(
+            "{}{}",
+            params.get("from_date"),
+            if by_time {
+                params.get("from_time")
+            } else {
+                "000000"
+            }
+        );
+        let to = format!(
+            "{}{}",
+            params.get("to_date"),
+            if by_time {
+                params.get("to_time")
+            } else {
+                "235959"
+            }
+        );
+        let mut entries: Vec<&LedgerEntry> = self
+            .ledger
+            .iter()
+            .filter(|entry| {
+                entry.fintech_use_num == fintech_use_num
+                    && match params.get("inquiry_type") {
+                        "I" => entry.deposit,
+                        "O" => !entry.deposit,
+                        _ => true,
+                    }
+                    && (from.as_str()..=to.as_str())
+                        .contains(&format!("{}{}", entry.tran_date, entry.tran_time).as_str())
+            })
+            .collect();
+        if params.get("sort_order") == "D" {
+            entries.reverse();
+        }
+        let offset: usize = params.get("befor_inquiry_trace_info").parse().unwrap_or(0);
+        let page: Vec<Value> = entries
+            .iter()
+            .skip(offset)
+            .take(TRANSACTION_PAGE_SIZE)
+            .map(|entry| {
+                json!({
+                    "tran_date": entry.tran_date,
+                    "tran_time": entry.tran_time,
+                    "inout_type": if entry.deposit { "입금" } else { "출금" },
+                    "tran_type": "대체",
+                    "print_content": entry.print_content,
+                    "tran_amt": entry.tran_amt.to_string(),
+                    "after_balance_amt": entry.after_balance_amt.to_string(),
+                })
+            })
+            .collect();
+        let next_offset = offset + page.len();
+        (page, (next_offset < entries.len()).then_some(next_offset))
+    }
+
+    fn oob_inquiry(&mut self, endpoint: InquiryEndpoint, params: &Params) -> Reply {
+        if endpoint == InquiryEndpoint::RemitList {
+            return self.remit_list(params);
+        }
+        let Some(account) = self
+            .find_account(params)
+            .map(|index| self.accounts[index].clone())
+        else {
+            return self.bank_error("계좌번호 오류");
+        };
+        let today = now_kst().format("%Y%m%d").to_string();
+        let mut fields = json!({
+            "bank_tran_id": params.get("bank_tran_id"),
+            "bank_tran_date": today,
+            "bank_code_tran": account.bank_code_std,
+            "bank_rsp_code": BANK_RSP_SUCCESS,
+            "bank_rsp_message": "",
+            "bank_code_std": account.bank_code_std,
+            "account_num": account.account_num,
+            "account_holder_name": account.account_holder_name,
+        });
+        if endpoint == InquiryEndpoint::RealName {
+            fields["account_holder_info_type"] = json!(params.get("account_holder_info_type"));
+            fields["account_holder_info"] = json!(params.get("account_holder_info"));
+            fields["account_type"] = json!("1");
+        } else {
+            fields["print_content"] = json!(params.get("print_content"));
+            fields["tran_amt"] = json!(params.get("tran_amt"));
+        }
+        self.success(fields)
+    }
+
+    fn remit_list(&mut self, params: &Params) -> Reply {
+        let (from_date, to_date) = (params.get("from_date"), params.get("to_date"));
+        let mut records: Vec<&TransferRecord> = self
+            .transfers
+            .values()
+            .filter(|record| {
+                record.check_type == "1"
+                    && record.bank_rsp_code == BANK_RSP_SUCCESS
+                    && record.cntr_account_num == params.get("cntr_account_num")
+                    && (from_date..=to_date).contains(&record.bank_tran_date.as_str())
+            })
+            .collect();
+        records.sort_by(|a, b| {
+            (&a.bank_tran_date, &a.tran_time).cmp(&(&b.bank_tran_date, &b.tran_time))
+        });
+        if params.get("sort_order") == "D" {
+            records.reverse();
+        }
+        let res_list: Vec<Value> = records
+            .into_iter()
+            .map(|record| {
+                json!({
+                    "tran_date": record.bank_tran_date,
+                    "tran_time": record.tran_time,
+                    "print_content": record.print_content,
+                    "tran_amt": record.tran_amt.to_string(),
+                    "remitter_name": record.account.account_holder_name,
+                    "remitter_bank_code_std": record.account.bank_code_std,
+                    "remitter_account_num_masked": mask_account_num(&record.account.account_num),
+                })
+            })
+            .collect();
+        self.success(json!({
+            "bank_tran_id": params.get("bank_tran_id"),
+            "bank_tran_date": now_kst().format("%Y%m%d").to_string(),
+            "bank_rsp_code": BANK_RSP_SUCCESS,
+            "bank_rsp_message": "",
+            "account_num_masked": mask_account_num(params.get("cntr_account_num")),
+            "next_page_yn": "N",
+            "befor_inquiry_trace_info": "",
+            "res_cnt": res_list.len().to_string(),
+            "res_list": res_list,
+        }))
+    }
+
+    /// 이체를 처리하고 기록(출금계좌 잔액이 부족하면 거절된 거래로 기록)
+    /// - account: 출금이체는 출금계좌, 입금이체는 입금계좌
+    /// - cntr: 이용기관 약정계좌(mock에 등록된 경우 잔액에 반영)
+    fn transfer(
+        &mut self,
+        check_type: &'static str,
+        bank_tran_id: &str,
+        account: usize,
+        cntr: Option<usize>,
+        tran_amt: i64,
+        (cntr_account_num, print_content): (&str, &str),
+    ) -> TransferRecord {
+        let (debit, credit) = match check_type {
+            "1" => (Some(account), cntr),
+            _ => (cntr, Some(account)),
+        };
+        let insufficient = debit.is_some_and(|debit| self.accounts[debit].balance < tran_amt);
+        let now = now_kst();
+        if !insufficient {
+            for (index, deposit) in [(debit, false), (credit, true)] {
+                let Some(index) = index else { continue };
+                let account = &mut self.accounts[index];
+                account.balance += if deposit { tran_amt } else { -tran_amt };
+                self.ledger.push(LedgerEntry {
+                    fintech_use_num: account.fintech_use_num.clone(),
+                    tran_date: now.format("%Y%m%d").to_string(),
+                    tran_time: now.format("%H%M%S").to_string(),
+                    deposit,
+                    print_content: print_content.to_string(),
+                    tran_amt,
+                    after_balance_amt: account.balance,
+                });
+            }
+        }
+        let record = TransferRecord {
+            check_type,
+            bank_tran_date: now.format("%Y%m%d").to_string(),
+            tran_time: now.format("%H%M%S").to_string(),
+            tran_amt,
+            bank_rsp_code: if insufficient {
+                BANK_RSP_REJECTED
+            } else {
+                BANK_RSP_SUCCESS
+            },
+            bank_rsp_message: if insufficient { "잔액부족" } else { "" },
+            cntr_account_num: cntr_account_num.to_string(),
+            account: self.accounts[account].clone(),
+            print_content: print_content.to_string(),
+        };
+        self.transfers
+            .insert(bank_tran_id.to_string(), record.clone());
+        record
+    }
+
+    fn withdraw(&mut self, token: &MockToken, params: &Params) -> Reply {
+        let bank_tran_id = params.get("bank_tran_id");
+        if self.transfers.contains_key(bank_tran_id) {
+            return self.error(ResponseCode::DuplicateBankTranId, "은행거래고유번호 중복");
+        }
+        let Some(tran_amt) = params.amount("tran_amt") else {
+            return self.error(ResponseCode::RequestFormatError, "거래금액 오류");
+        };
+        let account = self.find_account(params).filter(|index| {
+            params.get("fintech_use_num").is_empty()
+                || self.accounts[*index].user_seq_no == token.user_seq_no
+        });
+        let Some(account) = account else {
+            return self.bank_error("등록되지 않은 계좌");
+        };
+        if !self.accounts[account].transfer_agreed {
+            return self.error(ResponseCode::AccessDenied, "출금서비스 동의 없음");
+        }
+        let remaining_limit = self.remaining_withdraw_limit(account);
+        if remaining_limit.is_some_and(|remaining| remaining < tran_amt) {
+            return self.bank_error("출금한도 초과");
+        }
+        let cntr = self.find_cntr_account(params);
+        let record = self.transfer(
+            "1",
+            bank_tran_id,
+            account,
+            cntr,
+            tran_amt,
+            (
+                params.get("cntr_account_num"),
+                params.get("dps_print_content"),
+            ),
+        );
+        if record.bank_rsp_code != BANK_RSP_SUCCESS {
+            return self.bank_error(record.bank_rsp_message);
+        }
+        let dps = cntr.map(|cntr| self.accounts[cntr].clone());
+        self.success(json!({
+            "dps_bank_code_std": dps.as_ref().map(|dps| dps.bank_code_std.clone()),
+            "dps_account_num_masked": mask_account_num(params.get("cntr_account_num")),
+            "dps_print_content": record.print_content,
+            "dps_account_holder_name": dps.as_ref().map(|dps| dps.account_holder_name.clone()),
+            "bank_tran_id": bank_tran_id,
+            "bank_tran_date": record.bank_tran_date,
+            "bank_code_tran": record.account.bank_code_std,
+            "bank_rsp_code": record.bank_rsp_code,
+            "bank_rsp_message": record.bank_rsp_message,
+            "fintech_use_num": record.account.fintech_use_num,
+            "bank_code_std": record.account.bank_code_std,
+            "account_num_masked": mask_account_num(&record.account.account_num),
+            "print_content": params.get("dps_print_content"),
+            "tran_amt": tran_amt.to_string(),
+            "account_holder_name": record.account.account_holder_name,
+            "wd_limit_remain_amt": remaining_limit.map(|remaining| (remaining - tran_amt).to_string()),
+        }))
+    }
+
+    /// 오늘 출금이체로 더 출금할 수 있는 금액(한도가 없으면 None)
+    fn remaining_withdraw_limit(&self, account: usize) -> Option<i64> {
+        let account = &self.accounts[account];
+        let limit = account.daily_withdraw_limit?;
+        let today = now_kst().format("%Y%m%d").to_string();
+        let withdrawn: i64 = self
+            .transfers
+            .values()
+            .filter(|record| {
+                record.check_type == "1"
+                    && record.bank_rsp_code == BANK_RSP_SUCCESS
+                    && record.bank_tran_date == today
+                    && record.account.fintech_use_num == account.fintech_use_num
+            })
+            .map(|record| record.tran_amt)
+            .sum();
+        Some(limit - withdrawn)
+    }
+
+    fn deposit(&mut self, params: &Params) -> Reply {
+        let cntr = self.find_cntr_account(params);
+        let mut res_list = Vec::new();
+        for item in params.list("req_list") {
+            let bank_tran_id = item.get("bank_tran_id");
+            let mut res_item = json!({
+                "tran_no": item.get("tran_no"),
+                "bank_tran_id": bank_tran_id,
+                "bank_rsp_code": BANK_RSP_REJECTED,
+                "tran_amt": item.get("tran_amt"),
+            });
+            let account = self.find_account(&item);
+            match (account, item.amount("tran_amt")) {
+                _ if self.transfers.contains_key(bank_tran_id) => {
+                    res_item["bank_rsp_message"] = json!("은행거래고유번호 중복");
+                }
+                (Some(account), Some(tran_amt)) => {
+                    let record = self.transfer(
+                        "2",
+                        bank_tran_id,
+                        account,
+                        cntr,
+                        tran_amt,
+                        (params.get("cntr_account_num"), item.get("print_content")),
+                    );
+                    res_item["bank_tran_date"] = json!(record.bank_tran_date);
+                    res_item["bank_code_tran"] = json!(record.account.bank_code_std);
+                    res_item["bank_rsp_code"] = json!(record.bank_rsp_code);
+                    res_item["bank_rsp_message"] = json!(record.bank_rsp_message);
+                    res_item["fintech_use_num"] = json!(record.account.fintech_use_num);
+                    res_item["bank_code_std"] = json!(record.account.bank_code_std);
+                    res_item["account_num_masked"] =
+                        json!(mask_account_num(&record.account.account_num));
+                    res_item["print_content"] = json!(record.print_content);
+                    res_item["account_holder_name"] = json!(record.account.account_holder_name);
+                }
+                (None, _) => res_item["bank_rsp_message"] = json!("계좌번호 오류"),
+                (_, None) => res_item["bank_rsp_message"] = json!("거래금액 오류"),
+            }
+            res_list.push(res_item);
+        }
+        let wd = cntr.map(|cntr| self.accounts[cntr].clone());
+        // 일부 건이라도 실패하면 응답코드(API)는 참가기관 오류이고 건별 결과는 res_list에 담김
+        let (rsp_code, rsp_message) = if res_list
+            .iter()
+            .all(|res_item| res_item["bank_rsp_code"] == BANK_RSP_SUCCESS)
+        {
+            (ResponseCode::ApiSuccess, "")
+        } else {
+            (ResponseCode::BankError, "참가기관 오류")
+        };
+        self.reply(
+            rsp_code,
+            rsp_message,
+            json!({
+                "wd_bank_code_std": wd.as_ref().map(|wd| wd.bank_code_std.clone()),
+                "wd_account_num_masked": mask_account_num(params.get("cntr_account_num")),
+                "wd_print_content": params.get("wd_print_content"),
+                "wd_account_holder_name": wd.as_ref().map(|wd| wd.account_holder_name.clone()),
+                "res_cnt": res_list.len().to_string(),
+                "res_list": res_list,
+            }),
+        )
+    }
+
+    fn transfer_result(&mut self, params: &Params) -> Reply {
+        let check_type = params.get("check_type");
+        let res_list: Vec<Value> = params
+            .list("req_list")
+            .into_iter()
+            .map(|item| {
+                let org_bank_tran_id = item.get("org_bank_tran_id");
+                let mut res_item = json!({
+                    "tran_no": item.get("tran_no"),
+                    "check_type": check_type,
+                    "org_bank_tran_id": org_bank_tran_id,
+                    "org_bank_tran_date": item.get("org_bank_tran_date"),
+                    "org_tran_amt": item.get("org_tran_amt"),
+                    "bank_rsp_code": BANK_RSP_REJECTED,
+                    "bank_rsp_message": "해당 거래 없음",
+                });
+                if let Some(record) = self
+                    .transfers
+                    .get(org_bank_tran_id)
+                    .filter(|record| record.check_type == check_type)
+                {
+                    res_item["bank_tran_id"] = json!(org_bank_tran_id);
+                    res_item["bank_tran_date"] = json!(record.bank_tran_date);
+                    res_item["bank_code_tran"] = json!(record.account.bank_code_std);
+                    res_item["bank_rsp_code"] = json!(record.bank_rsp_code);
+                    res_item["bank_rsp_message"] = json!(record.bank_rsp_message);
+                    res_item["bank_code_std"] = json!(record.account.bank_code_std);
+                    res_item["account_num_masked"] =
+                        json!(mask_account_num(&record.account.account_num));
+                    res_item["account_holder_name"] = json!(record.account.account_holder_name);
+                    res_item["tran_amt"] = json!(record.tran_amt.to_string());
+                }
+                res_item
+            })
+            .collect();
+        self.success(json!({
+            "res_cnt": res_list.len().to_string(),
+            "res_list": res_list,
+        }))
+    }
+}
@@ -0,0 +1,304 @@
+//! 여러 이용기관(tenant)을 한 프로세스에서 사용
+//! - Tenant: 이용기관별 설정, OAuthApi(호출량 제한 포함), 토큰 저장소, 은행거래고유번호 생성기
+//! - TenantRegistry: tenant key로 Tenant를 찾아 호출(tenant마다 RateLimiter가 따로 있어 한 tenant의 호출량 제한이 다른 tenant를 막지 않음)
+use crate::api::oauth::{OAuthApi, OAuthApiBuilder};
+use crate::bank_tran_id::BankTranIdGenerator;
+use crate::config::ClientConfig;
+use crate::types::{oauth::token, secret::Secret};
+use crate::ApiError;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, SystemTime},
+};
+
+/// 이용기관 토큰(2-legged)을 저장하는 key(사용자 토큰은 사용자일련번호를 key로 저장)
+pub const OOB_TOKEN_KEY: &str = "oob";
+/// 만료 직전의 토큰으로 호출하지 않도록 만료시각보다 일찍 만료된 것으로 보는 시간
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// 저장된 토큰
+/// - access_token: Access Token
+/// - refresh_token: Refresh Token(사용자 토큰)
+/// - scope: Access Token 권한 범위
+/// - user_seq_no: 사용자일련번호(사용자 토큰)
+/// - expires_at: 만료시각
+#[derive(Debug, Clone)]
+pub struct StoredToken {
+    access_token: Secret,
+    refresh_token: Option<Secret>,
+    scope: Option<String>,
+    user_seq_no: Option<String>,
+    expires_at: Option<SystemTime>,
+}
+
+impl StoredToken {
+    /// 토큰발급 응답으로 생성(Access Token이 없으면 None)
+    pub fn from_response(response: &token::ResponseBody) -> Option<Self> {
+        Some(Self {
+            access_token: Secret::new(response.access_token()?.to_string()),
+            refresh_token: response
+                .refresh_token()
+                .map(|refresh_token| Secret::new(refresh_token.to_string())),
+            scope: response.scope().map(str::to_string),
+            user_seq_no: response.user_seq_no().map(str::to_string),
+            expires_at: response
+                .expires_in()
+                .map(|expires_in| SystemTime::now() + Duration::from_secs(expires_in)),
+        })
+    }
+
+    pub fn access_token(&self) -> &str {
+        self.access_token.expose()
+    }
+
+    pub fn refresh_token(&self) -> Option<&str> {
+        self.refresh_token.as_ref().map(Secret::expose)
+    }
+
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    pub fn user_seq_no(&self) -> Option<&str> {
+        self.user_seq_no.as_deref()
+    }
+
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+    }
+
+    /// 만료되었거나 곧 만료되는지 여부(만료시각을 모르면 만료되지 않은 것으로 봄)
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| SystemTime::now() + EXPIRY_MARGIN >= expires_at)
+    }
+}
+
+/// tenant별 토큰 저장소
+/// - 여러 인스턴스가 토큰을 공유하려면 DB/Redis 등 영속 저장소로 구현
+pub trait TokenStore: Send + Sync {
+    fn get(
+        &self,
+        key: &str,
+    ) -> Result<Option<StoredToken>, Box<dyn std::error::Error + Send + Sync>>;
+    fn put(
+        &self,
+        key: &str,
+        token: StoredToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+    fn remove(&self, key: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// 프로세스 내 HashMap 기반 토큰 저장소
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    tokens: Mutex<HashMap<String, StoredToken>>,
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn get(
+        &self,
+        key: &str,
+    ) -> Result<Option<StoredToken>, Box<dyn std::error::Error + Send + Sync>> {
+        let tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(tokens.get(key).cloned())
+    }
+
+    fn put(
+        &self,
+        key: &str,
+        token: StoredToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        tokens.insert(key.to_string(), token);
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        tokens.remove(key);
+        Ok(())
+    }
+}
+
+/// 이용기관(tenant)
+/// - api: 이용기관 설정으로 구성한 OAuthApi
+/// - token_store: 토큰 저장소
+/// - bank_tran_id: 이용기관코드로 구성한 은행거래고유번호 생성기
+/// - oob_token_refresh: 이용기관 토큰을 한 번에 하나만 발급하도록 잡는 lock
+pub struct Tenant {
+    api: OAuthApi,
+    token_store: Arc<dyn TokenStore>,
+    bank_tran_id: BankTranIdGenerator,
+    oob_token_refresh: tokio::sync::Mutex<()>,
+}
+
+impl Tenant {
+    /// 이용기관 설정으로 생성(HTTP 설정은 기본값, RateLimiter/토큰 저장소/일련번호는 tenant 전용)
+    pub fn new(config: ClientConfig) -> Result<Self, ApiError> {
+        Self::build(config, OAuthApi::builder())
+    }
+
+    /// 이용기관 설정과 OAuthApi builder(transport, 재시도 정책, 호출량 제한 등)로 생성
+    /// - builder에 rate_limiter를 지정하지 않으면 tenant 전용 RateLimiter 사용
+    pub fn build(config: ClientConfig, builder: OAuthApiBuilder) -> Result<Self, ApiError> {
+        let bank_tran_id = config.bank_tran_id_generator();
+        Ok(Self {
+            api: builder.config(config).build()?,
+            token_store: Arc::new(MemoryTokenStore::default()),
+            bank_tran_id,
+            oob_token_refresh: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    /// 토큰 저장소 설정
+    pub fn with_token_store(mut self, token_store: Arc<dyn TokenStore>) -> Self {
+        self.token_store = token_store;
+        self
+    }
+
+    /// 은행거래고유번호 생성기 설정(일련번호를 영속 저장소에 두는 경우)
+    pub fn with_bank_tran_id_generator(mut self, bank_tran_id: BankTranIdGenerator) -> Self {
+        self.bank_tran_id = bank_tran_id;
+        self
+    }
+
+    pub fn api(&self) -> &OAuthApi {
+        &self.api
+    }
+
+    pub fn config(&self) -> &ClientConfig {
+        self.api
+            .config()
+            .expect("tenant api is always built with a client config")
+    }
+
+    pub fn token_store(&self) -> &dyn TokenStore {
+        self.token_store.as_ref()
+    }
+
+    pub fn bank_tran_id_generator(&self) -> &BankTranIdGenerator {
+        &self.bank_tran_id
+    }
+
+    /// 새 은행거래고유번호
+    pub fn next_bank_tran_id(&self) -> Result<String, ApiError> {
+        self.bank_tran_id.next()
+    }
+
+    /// 저장된 이용기관 토큰(2-legged)을 반환하고, 없거나 만료되었으면 새로 발급해 저장
+    /// - 여러 요청이 동시에 만료를 발견해도 토큰발급은 한 번만 호출하고 나머지는 발급된 토큰을 사용
+    pub async fn oob_token(&self) -> Result<String, ApiError> {
+        if let Some(token) = self.stored_token(OOB_TOKEN_KEY)? {
+            return Ok(token.access_token().to_string());
+        }
+        let _refresh = self.oob_token_refresh.lock().await;
+        // lock을 기다리는 동안 다른 요청이 발급한 토큰
+        if let Some(token) = self.stored_token(OOB_TOKEN_KEY)? {
+            return Ok(token.access_token().to_string());
+        }
+        let response = self.api.issue_oob_token().await?;
+        let Some(token) = StoredToken::from_response(&response) else {
+            return Err(ApiError::TokenStore(
+                "token response has no access_token to store".into(),
+            ));
+        };
+        let access_token = token.access_token().to_string();
+        self.token_store
+            .put(OOB_TOKEN_KEY, token)
+            .map_err(ApiError::TokenStore)?;
+        Ok(access_token)
+    }
+
+    /// 사용자 토큰(3-legged)을 발급해 사용자일련번호로 저장
+    pub async fn issue_user_token(&self, code: String) -> Result<token::ResponseBody, ApiError> {
+        let response = self.api.issue_token(code).await?;
+        if let (Some(user_seq_no), Some(token)) = (
+            response.user_seq_no(),
+            StoredToken::from_response(&response),
+        ) {
+            self.token_store
+                .put(user_seq_no, token)
+                .map_err(ApiError::TokenStore)?;
+        }
+        Ok(response)
+    }
+
+    /// 저장된 사용자 토큰(없거나 만료되었으면 None)
+    pub fn user_token(&self, user_seq_no: &str) -> Result<Option<String>, ApiError> {
+        Ok(self
+            .stored_token(user_seq_no)?
+            .map(|token| token.access_token().to_string()))
+    }
+
+    /// 저장된 사용자 토큰을 폐기하고 저장소에서 삭제
+    pub async fn revoke_user_token(&self, user_seq_no: &str) -> Result<(), ApiError> {
+        let token = self
+            .token_store
+            .get(user_seq_no)
+            .map_err(ApiError::TokenStore)?;
+        if let Some(token) = token {
+            self.api
+                .revoke_token(token.access_token().to_string())
+                .await?;
+        }
+        self.token_store
+            .remove(user_seq_no)
+            .map_err(ApiError::TokenStore)
+    }
+
+    fn stored_token(&self, key: &str) -> Result<Option<StoredToken>, ApiError> {
+        let token = self.token_store.get(key).map_err(ApiError::TokenStore)?;
+        Ok(token.filter(|token| !token.is_expired()))
+    }
+}
+
+/// tenant key별 Tenant 목록
+#[derive(Default)]
+pub struct TenantRegistry {
+    tenants: RwLock<HashMap<String, Arc<Tenant>>>,
+}
+
+impl TenantRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// tenant 등록(같은 key로 등록된 tenant는 교체)
+    pub fn register(&self, key: String, tenant: Tenant) -> Arc<Tenant> {
+        let tenant = Arc::new(tenant);
+        self.write().insert(key, tenant.clone());
+        tenant
+    }
+
+    /// tenant 삭제
+    pub fn remove(&self, key: &str) -> Option<Arc<Tenant>> {
+        self.write().remove(key)
+    }
+
+    /// tenant key로 Tenant를 찾음(등록되지 않은 key면 ApiError::UnknownTenant)
+    pub fn get(&self, key: &str) -> Result<Arc<Tenant>, ApiError> {
+        self.tenants
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(key)
+            .cloned()
+            .ok_or_else(|| ApiError::UnknownTenant {
+                key: key.to_string(),
+            })
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        self.tenants
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<String, Arc<Tenant>>> {
+        self.tenants.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,243 @@
+use chrono::{
+    DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, ParseResult, TimeZone, Utc,
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+const KST_OFFSET_SECS: i32 = 9 * 3600;
+
+/// Asia/Seoul(UTC+9, 서머타임 없음) 시간대
+pub fn kst() -> FixedOffset {
+    FixedOffset::east_opt(KST_OFFSET_SECS).expect("KST offset is in range")
+}
+
+/// 현재 KST 시각
+pub fn now_kst() -> DateTime<FixedOffset> {
+    Utc::now().with_timezone(&kst())
+}
+
+fn from_kst(naive: NaiveDateTime) -> DateTime<FixedOffset> {
+    kst()
+        .from_local_datetime(&naive)
+        .single()
+        .expect("KST has no ambiguous local times")
+}
+
+/// KFTC 형식 문자열로 직렬화/역직렬화되는 타입을 정의
+macro_rules! kftc_format {
+    ($name:ident) => {
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0.format(Self::FORMAT))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = chrono::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+/// 요청일시(YYYYMMDDhhmmss, KST)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TranDtime(DateTime<FixedOffset>);
+
+impl TranDtime {
+    const FORMAT: &'static str = "%Y%m%d%H%M%S";
+
+    pub fn now() -> Self {
+        Self(now_kst())
+    }
+
+    pub fn datetime(&self) -> DateTime<FixedOffset> {
+        self.0
+    }
+
+    pub fn date(&self) -> Date {
+        Date(self.0.date_naive())
+    }
+
+    fn parse(s: &str) -> ParseResult<Self> {
+        NaiveDateTime::parse_from_str(s, Self::FORMAT).map(|naive| Self(from_kst(naive)))
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for TranDtime {
+    fn from(datetime: DateTime<Tz>) -> Self {
+        Self(datetime.with_timezone(&kst()))
+    }
+}
+
+kftc_format!(TranDtime);
+
+/// 거래일시(YYYYMMDDhhmmssSSS, 밀리세컨드, KST)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ApiTranDtm(DateTime<FixedOffset>);
+
+impl ApiTranDtm {
+    const FORMAT: &'static str = "%Y%m%d%H%M%S%3f";
+
+    pub fn now() -> Self {
+        Self(now_kst())
+    }
+
+    pub fn datetime(&self) -> DateTime<FixedOffset> {
+        self.0
+    }
+
+    fn parse(s: &str) -> ParseResult<Self> {
+        NaiveDateTime::parse_from_str(s, Self::FORMAT).map(|naive| Self(from_kst(naive)))
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for ApiTranDtm {
+    fn from(datetime: DateTime<Tz>) -> Self {
+        Self(datetime.with_timezone(&kst()))
+    }
+}
+
+kftc_format!(ApiTranDtm);
+
+/// 일자(YYYYMMDD, KST)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date(NaiveDate);
+
+impl Date {
+    const FORMAT: &'static str = "%Y%m%d";
+
+    pub fn today() -> Self {
+        Self(now_kst().date_naive())
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.0
+    }
+
+    fn parse(s: &str) -> ParseResult<Self> {
+        NaiveDate::parse_from_str(s, Self::FORMAT).map(Self)
+    }
+}
+
+impl From<NaiveDate> for Date {
+    fn from(date: NaiveDate) -> Self {
+        Self(date)
+    }
+}
+
+kftc_format!(Date);
+
+/// 시간(hhmmss, KST)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time(NaiveTime);
+
+impl Time {
+    const FORMAT: &'static str = "%H%M%S";
+
+    pub fn time(&self) -> NaiveTime {
+        self.0
+    }
+
+    fn parse(s: &str) -> ParseResult<Self> {
+        NaiveTime::parse_from_str(s, Self::FORMAT).map(Self)
+    }
+}
+
+impl From<NaiveTime> for Time {
+    fn from(time: NaiveTime) -> Self {
+        Self(time)
+    }
+}
+
+kftc_format!(Time);
+
+/// 오류 응답에서 빈 문자열로 오는 일자/시간 필드를 None으로 역직렬화
+pub(crate) fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) if !s.trim().is_empty() => s.parse().map(Some).map_err(de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tran_dtime_is_formatted_in_kst() {
+        let utc = Utc.with_ymd_and_hms(2023, 12, 31, 15, 30, 5).unwrap();
+        let tran_dtime = TranDtime::from(utc);
+        assert_eq!(tran_dtime.to_string(), "20240101003005");
+        assert_eq!(tran_dtime.date().to_string(), "20240101");
+        assert_eq!(
+            tran_dtime.datetime().offset().local_minus_utc(),
+            KST_OFFSET_SECS
+        );
+    }
+
+    #[test]
+    fn tran_dtime_round_trips_through_kftc_format() {
+        let tran_dtime: TranDtime = "20240101003005".parse().unwrap();
+        assert_eq!(
+            tran_dtime.datetime(),
+            Utc.with_ymd_and_hms(2023, 12, 31, 15, 30, 5).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_string(&tran_dtime).unwrap(),
+            "\"20240101003005\""
+        );
+        let decoded: TranDtime = serde_json::from_str("\"20240101003005\"").unwrap();
+        assert_eq!(decoded, tran_dtime);
+        assert!("2024-01-01 00:30:05".parse::<TranDtime>().is_err());
+    }
+
+    #[test]
+    fn api_tran_dtm_keeps_milliseconds() {
+        let api_tran_dtm: ApiTranDtm = "20240101003005123".parse().unwrap();
+        assert_eq!(api_tran_dtm.to_string(), "20240101003005123");
+        assert_eq!(api_tran_dtm.datetime().timestamp_subsec_millis(), 123);
+    }
+
+    #[test]
+    fn date_and_time_parse_kftc_format() {
+        let date: Date = "20240229".parse().unwrap();
+        assert_eq!(date.date(), NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert!("20230229".parse::<Date>().is_err());
+        let time: Time = "235959".parse().unwrap();
+        assert_eq!(time.to_string(), "235959");
+    }
+
+    #[test]
+    fn empty_strings_deserialize_as_none() {
+        #[derive(Deserialize)]
+        struct Fields {
+            #[serde(default, deserialize_with = "empty_as_none")]
+            date: Option<Date>,
+        }
+        let fields: Fields = serde_json::from_str(r#"{"date":""}"#).unwrap();
+        assert!(fields.date.is_none());
+        let fields: Fields = serde_json::from_str(r#"{"date":"20240101"}"#).unwrap();
+        assert_eq!(fields.date.unwrap().to_string(), "20240101");
+    }
+}
@@ -1,14 +1,65 @@
-#[cfg(feature = "prod")]
-const ENDPOINT_URL: &'static str = "https://openapi.openbanking.or.kr";
-#[cfg(feature = "test")]
-const ENDPOINT_URL: &'static str = "https://testapi.openbanking.or.kr";
+use serde::{Deserialize, Serialize};
 
+/// 오픈뱅킹 API 환경(미지정 시 `prod` feature가 켜져 있으면 운영, 아니면 테스트)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Environment {
+    Test, // 테스트(testapi.openbanking.or.kr)
+    Prod, // 운영(openapi.openbanking.or.kr)
+}
+
+impl Environment {
+    pub fn base_url(&self) -> &'static str {
+        match self {
+            Self::Test => "https://testapi.openbanking.or.kr",
+            Self::Prod => "https://openapi.openbanking.or.kr",
+        }
+    }
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        if cfg!(feature = "prod") {
+            Self::Prod
+        } else {
+            Self::Test
+        }
+    }
+}
+
+impl std::str::FromStr for Environment {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "test" => Ok(Self::Test),
+            "prod" => Ok(Self::Prod),
+            _ => Err(format!(
+                "unknown environment {:?} (expected \"test\" or \"prod\")",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Endpoint {
-    OAuth(OAuthEndpoint),     // 사용자인증(OAuth 2.0)
-    User(UserEndpoint),       // 사용자/계좌관리
-    Inquiry(InquiryEndpoint), // 조회서비스
+    OAuth(OAuthEndpoint),       // 사용자인증(OAuth 2.0)
+    User(UserEndpoint),         // 사용자/계좌관리
+    Inquiry(InquiryEndpoint),   // 조회서비스
+    Transfer(TransferEndpoint), // 이체서비스
+}
+
+/// 호출량 제한을 적용하는 API 그룹
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointGroup {
+    OAuth,
+    User,
+    Inquiry,
+    Transfer,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum OAuthEndpoint {
     Authorize,        // 사용자인증(GET)
     Token,            // 토큰발급(POST)
@@ -16,6 +67,7 @@ pub enum OAuthEndpoint {
     AuthorizeAccount, // 서비스등록확인(POST)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UserEndpoint {
     UserInfo,         // 사용자정보조회(GET)
     Unlink,           // 사용자로그인연결동의해체(POST)
@@ -29,6 +81,7 @@ pub enum UserEndpoint {
     Quit,             // 사용자탈퇴(POST)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum InquiryEndpoint {
     Balance,         // 잔액조회(GET)
     ListTransaction, // 거래내역조회(GET)
@@ -37,64 +90,215 @@ pub enum InquiryEndpoint {
     Receive,         // 수취조회(POST)
 }
 
-impl Into<String> for Endpoint {
-    fn into(self) -> String {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransferEndpoint {
+    WithdrawFinNum,  // 출금이체(핀테크이용번호 사용)(POST)
+    WithdrawAcntNum, // 출금이체(계좌번호 사용)(POST)
+    DepositFinNum,   // 입금이체(핀테크이용번호 사용)(POST)
+    DepositAcntNum,  // 입금이체(계좌번호 사용)(POST)
+    Result,          // 이체결과조회(POST)
+}
+
+impl Endpoint {
+    pub fn group(&self) -> EndpointGroup {
+        match self {
+            Self::OAuth(_) => EndpointGroup::OAuth,
+            Self::User(_) => EndpointGroup::User,
+            Self::Inquiry(_) => EndpointGroup::Inquiry,
+            Self::Transfer(_) => EndpointGroup::Transfer,
+        }
+    }
+
+    /// 같은 요청을 다시 보내도 안전한지 여부
+    /// - 요청에 bank_tran_id를 담는 조회서비스/출금이체/입금이체는 같은 bank_tran_id로 재요청하면
+    ///   A0019(은행거래고유번호 중복)로 거절되거나 이체가 중복 처리될 수 있으므로 false
+    pub fn is_idempotent(&self) -> bool {
+        !matches!(
+            self,
+            Self::Inquiry(_)
+                | Self::Transfer(
+                    TransferEndpoint::WithdrawFinNum
+                        | TransferEndpoint::WithdrawAcntNum
+                        | TransferEndpoint::DepositFinNum
+                        | TransferEndpoint::DepositAcntNum
+                )
+        )
+    }
+
+    /// 지표 label 등에 쓰는 고정 이름(예: "inquiry.balance")
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Self::OAuth(inner) => inner.into(),
-            Self::User(inner) => inner.into(),
-            Self::Inquiry(inner) => inner.into(),
+            Self::OAuth(OAuthEndpoint::Authorize) => "oauth.authorize",
+            Self::OAuth(OAuthEndpoint::Token) => "oauth.token",
+            Self::OAuth(OAuthEndpoint::Revoke) => "oauth.revoke",
+            Self::OAuth(OAuthEndpoint::AuthorizeAccount) => "oauth.authorize_account",
+            Self::User(UserEndpoint::UserInfo) => "user.user_info",
+            Self::User(UserEndpoint::Unlink) => "user.unlink",
+            Self::User(UserEndpoint::ListAccount) => "user.list_account",
+            Self::User(UserEndpoint::UpdateAccount) => "user.update_account",
+            Self::User(UserEndpoint::CancelAccount) => "user.cancel_account",
+            Self::User(UserEndpoint::CancelCard) => "user.cancel_card",
+            Self::User(UserEndpoint::CancelPays) => "user.cancel_pays",
+            Self::User(UserEndpoint::CancelInsurances) => "user.cancel_insurances",
+            Self::User(UserEndpoint::CancelLoans) => "user.cancel_loans",
+            Self::User(UserEndpoint::Quit) => "user.quit",
+            Self::Inquiry(InquiryEndpoint::Balance) => "inquiry.balance",
+            Self::Inquiry(InquiryEndpoint::ListTransaction) => "inquiry.list_transaction",
+            Self::Inquiry(InquiryEndpoint::RealName) => "inquiry.real_name",
+            Self::Inquiry(InquiryEndpoint::RemitList) => "inquiry.remit_list",
+            Self::Inquiry(InquiryEndpoint::Receive) => "inquiry.receive",
+            Self::Transfer(TransferEndpoint::WithdrawFinNum) => "transfer.withdraw_fin_num",
+            Self::Transfer(TransferEndpoint::WithdrawAcntNum) => "transfer.withdraw_acnt_num",
+            Self::Transfer(TransferEndpoint::DepositFinNum) => "transfer.deposit_fin_num",
+            Self::Transfer(TransferEndpoint::DepositAcntNum) => "transfer.deposit_acnt_num",
+            Self::Transfer(TransferEndpoint::Result) => "transfer.result",
         }
     }
+
+    /// API 경로(예: "/v2.0/user/me")
+    pub fn path(&self) -> String {
+        match self {
+            Self::OAuth(inner) => inner.path(),
+            Self::User(inner) => inner.path(),
+            Self::Inquiry(inner) => inner.path(),
+            Self::Transfer(inner) => inner.path(),
+        }
+    }
+
+    /// 해당 환경의 API URL
+    pub fn url(&self, environment: Environment) -> String {
+        format!("{}{}", environment.base_url(), self.path())
+    }
+}
+
+impl EndpointGroup {
+    /// 지표 label 등에 쓰는 고정 이름(예: "inquiry")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OAuth => "oauth",
+            Self::User => "user",
+            Self::Inquiry => "inquiry",
+            Self::Transfer => "transfer",
+        }
+    }
+}
+
+/// 기본 환경(Environment::default)의 API URL
+impl From<Endpoint> for String {
+    fn from(endpoint: Endpoint) -> Self {
+        endpoint.url(Environment::default())
+    }
+}
+
+impl OAuthEndpoint {
+    pub fn path(&self) -> String {
+        match self {
+            OAuthEndpoint::Authorize => "/oauth/2.0/authorize",
+            OAuthEndpoint::Token => "/oauth/2.0/token",
+            OAuthEndpoint::Revoke => "/oauth/2.0/revoke",
+            OAuthEndpoint::AuthorizeAccount => "/oauth/2.0/authorize_account",
+        }
+        .to_string()
+    }
 }
 
-impl Into<String> for OAuthEndpoint {
-    fn into(self) -> String {
+impl UserEndpoint {
+    pub fn path(&self) -> String {
         format!(
-            "{}{}",
-            ENDPOINT_URL,
+            "/v2.0{}",
             match self {
-                Self::Authorize => "/oauth/2.0/authorize",
-                Self::Token => "/oauth/2.0/token",
-                Self::Revoke => "/oauth/2.0/revoke",
-                Self::AuthorizeAccount => "/oauth/2.0/authorize_account",
+                UserEndpoint::UserInfo => "/user/me",
+                UserEndpoint::Unlink => "/user/unlink",
+                UserEndpoint::ListAccount => "/account/list",
+                UserEndpoint::UpdateAccount => "/account/update_info",
+                UserEndpoint::CancelAccount => "/account/cancel",
+                UserEndpoint::CancelCard => "/cards/cancel",
+                UserEndpoint::CancelPays => "/pays/cancel",
+                UserEndpoint::CancelInsurances => "/insurances/cancel",
+                UserEndpoint::CancelLoans => "/loans/cancel",
+                UserEndpoint::Quit => "/user/close",
             }
         )
     }
 }
 
-impl Into<String> for UserEndpoint {
-    fn into(self) -> String {
+impl InquiryEndpoint {
+    pub fn path(&self) -> String {
         format!(
-            "{}/v2.0{}",
-            ENDPOINT_URL,
+            "/v2.0{}",
             match self {
-                Self::UserInfo => "/user/me",
-                Self::Unlink => "/user/unlink",
-                Self::ListAccount => "/account/list",
-                Self::UpdateAccount => "/account/update_info",
-                Self::CancelAccount => "/account/cancel",
-                Self::CancelCard => "/cards/cancel",
-                Self::CancelPays => "/pays/cancel",
-                Self::CancelInsurances => "/insurances/cancel",
-                Self::CancelLoans => "/loans/cancel",
-                Self::Quit => "/user/close",
+                InquiryEndpoint::Balance => "/account/balance/fin_num",
+                InquiryEndpoint::ListTransaction => "/account/transaction_list/fin_num",
+                InquiryEndpoint::RealName => "/inquiry/real_name",
+                InquiryEndpoint::RemitList => "/inquiry/remit_list",
+                InquiryEndpoint::Receive => "/inquiry/receive",
             }
         )
     }
 }
 
-impl Into<String> for InquiryEndpoint {
-    fn into(self) -> String {
+impl TransferEndpoint {
+    pub fn path(&self) -> String {
         format!(
-            "{}/v2.0{}",
-            ENDPOINT_URL,
+            "/v2.0{}",
             match self {
-                Self::Balance => "/account/balance/fin_num",
-                Self::ListTransaction => "/account/transaction_list/fin_num",
-                Self::RealName => "/inquiry/real_name",
-                Self::RemitList => "/inquiry/remit_list",
-                Self::Receive => "/inquiry/receive",
+                TransferEndpoint::WithdrawFinNum => "/transfer/withdraw/fin_num",
+                TransferEndpoint::WithdrawAcntNum => "/transfer/withdraw/acnt_num",
+                TransferEndpoint::DepositFinNum => "/transfer/deposit/fin_num",
+                TransferEndpoint::DepositAcntNum => "/transfer/deposit/acnt_num",
+                TransferEndpoint::Result => "/transfer/result",
             }
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoints_carrying_bank_tran_id_are_not_idempotent() {
+        for endpoint in [
+            InquiryEndpoint::Balance,
+            InquiryEndpoint::ListTransaction,
+            InquiryEndpoint::RealName,
+            InquiryEndpoint::RemitList,
+            InquiryEndpoint::Receive,
+        ] {
+            assert!(!Endpoint::Inquiry(endpoint).is_idempotent());
+        }
+        for endpoint in [
+            TransferEndpoint::WithdrawFinNum,
+            TransferEndpoint::WithdrawAcntNum,
+            TransferEndpoint::DepositFinNum,
+            TransferEndpoint::DepositAcntNum,
+        ] {
+            assert!(!Endpoint::Transfer(endpoint).is_idempotent());
+        }
+    }
+
+    #[test]
+    fn endpoint_names_start_with_their_group() {
+        for endpoint in [
+            Endpoint::OAuth(OAuthEndpoint::Token),
+            Endpoint::User(UserEndpoint::CancelInsurances),
+            Endpoint::Inquiry(InquiryEndpoint::ListTransaction),
+            Endpoint::Transfer(TransferEndpoint::DepositAcntNum),
+        ] {
+            let (group, _) = endpoint.as_str().split_once('.').unwrap();
+            assert_eq!(group, endpoint.group().as_str());
+        }
+        assert_eq!(
+            Endpoint::Transfer(TransferEndpoint::WithdrawFinNum).as_str(),
+            "transfer.withdraw_fin_num"
+        );
+    }
+
+    #[test]
+    fn endpoints_without_bank_tran_id_are_idempotent() {
+        assert!(Endpoint::Transfer(TransferEndpoint::Result).is_idempotent());
+        assert!(Endpoint::User(UserEndpoint::UserInfo).is_idempotent());
+        assert!(Endpoint::User(UserEndpoint::ListAccount).is_idempotent());
+        assert!(Endpoint::OAuth(OAuthEndpoint::Token).is_idempotent());
+    }
+}
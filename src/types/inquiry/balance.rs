@@ -0,0 +1,114 @@
+use super::{BankResponseCode, ResponseCode};
+use crate::types::{
+    datetime::{empty_as_none, ApiTranDtm, Date, TranDtime},
+    HttpMethod,
+};
+use serde::{Deserialize, Serialize};
+
+pub const METHOD: HttpMethod = HttpMethod::Get;
+
+/// 잔액조회 API request query
+/// - bank_tran_id: 은행거래고유번호(이용기관코드 + "U" + 이용기관 부여번호 9자리)
+/// - fintech_use_num: 핀테크이용번호
+/// - tran_dtime: 요청일시(YYYYMMDDhhmmss)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RequestBody {
+    bank_tran_id: String,
+    fintech_use_num: String,
+    tran_dtime: TranDtime,
+}
+
+impl RequestBody {
+    pub fn new(bank_tran_id: String, fintech_use_num: String, tran_dtime: TranDtime) -> Self {
+        Self {
+            bank_tran_id,
+            fintech_use_num,
+            tran_dtime,
+        }
+    }
+}
+
+/// 잔액조회 API response body
+/// - api_tran_id: 거래고유번호(API)
+/// - api_tran_dtm: 거래일시(밀리세컨드)
+/// - rsp_code: 응답코드(API)(5자리)
+/// - rsp_message: 응답메시지(API)(300자리)
+/// - bank_tran_id: 거래고유번호(참가기관)
+/// - bank_tran_date: 거래일자(참가기관)
+/// - bank_code_tran: 응답코드를 부여한 참가기관.표준코드
+/// - bank_rsp_code: 응답코드(참가기관)(3자리)
+/// - bank_rsp_message: 응답메시지(참가기관)
+/// - bank_name: 개설기관명
+/// - savings_bank_name: 개별저축은행명
+/// - fintech_use_num: 핀테크이용번호
+/// - balance_amt: 계좌잔액(-금액 가능)
+/// - available_amt: 출금가능금액
+/// - account_type: 계좌종류("1": 수시입출금, "2": 예적금, "6": 수익증권, "T": 종합계좌)
+/// - product_name: 상품명
+/// - account_issue_date: 계좌개설일
+/// - maturity_date: 만기일
+/// - last_tran_date: 최종거래일
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseBody {
+    api_tran_id: String,
+    api_tran_dtm: ApiTranDtm,
+    rsp_code: ResponseCode,
+    rsp_message: String,
+    bank_tran_id: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    bank_tran_date: Option<Date>,
+    bank_code_tran: Option<String>,
+    bank_rsp_code: Option<BankResponseCode>,
+    bank_rsp_message: Option<String>,
+    bank_name: Option<String>,
+    savings_bank_name: Option<String>,
+    fintech_use_num: Option<String>,
+    balance_amt: Option<String>,
+    available_amt: Option<String>,
+    account_type: Option<String>,
+    product_name: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    account_issue_date: Option<Date>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    maturity_date: Option<Date>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    last_tran_date: Option<Date>,
+}
+
+impl ResponseBody {
+    pub fn api_tran_id(&self) -> &str {
+        &self.api_tran_id
+    }
+
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+
+    pub fn bank_rsp_code(&self) -> Option<&BankResponseCode> {
+        self.bank_rsp_code.as_ref()
+    }
+
+    pub fn bank_rsp_message(&self) -> Option<&str> {
+        self.bank_rsp_message.as_deref()
+    }
+
+    pub fn fintech_use_num(&self) -> Option<&str> {
+        self.fintech_use_num.as_deref()
+    }
+
+    pub fn balance_amt(&self) -> Option<&str> {
+        self.balance_amt.as_deref()
+    }
+
+    pub fn available_amt(&self) -> Option<&str> {
+        self.available_amt.as_deref()
+    }
+
+    pub fn product_name(&self) -> Option<&str> {
+        self.product_name.as_deref()
+    }
+}
@@ -0,0 +1,7 @@
+pub mod balance;
+pub mod real_name;
+pub mod receive;
+pub mod remit_list;
+pub mod transaction_list;
+
+pub use super::response_code::{BankResponseCode, ResponseCode};
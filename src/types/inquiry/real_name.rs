@@ -0,0 +1,114 @@
+use super::{BankResponseCode, ResponseCode};
+use crate::types::{
+    datetime::{empty_as_none, ApiTranDtm, Date, TranDtime},
+    secret::{AccountNum, Secret},
+    AccountHolderInfoType, HttpMethod,
+};
+use serde::{Deserialize, Serialize};
+
+pub const METHOD: HttpMethod = HttpMethod::Post;
+
+/// 계좌실명조회 API request body
+/// - bank_tran_id: 은행거래고유번호(이용기관코드 + "U" + 이용기관 부여번호 9자리)
+/// - bank_code_std: 개설기관.표준코드
+/// - account_num: 계좌번호
+/// - account_holder_info_type: 예금주 실명번호 구분코드
+/// - account_holder_info: 예금주 실명번호(생년월일 6자리 또는 사업자등록번호 10자리)
+/// - tran_dtime: 요청일시(YYYYMMDDhhmmss)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RequestBody {
+    bank_tran_id: String,
+    bank_code_std: String,
+    account_num: AccountNum,
+    account_holder_info_type: AccountHolderInfoType,
+    account_holder_info: Secret,
+    tran_dtime: TranDtime,
+}
+
+impl RequestBody {
+    pub fn new(
+        bank_tran_id: String,
+        bank_code_std: String,
+        account_num: String,
+        account_holder_info_type: AccountHolderInfoType,
+        account_holder_info: String,
+        tran_dtime: TranDtime,
+    ) -> Self {
+        Self {
+            bank_tran_id,
+            bank_code_std,
+            account_num: AccountNum::new(account_num),
+            account_holder_info_type,
+            account_holder_info: Secret::new(account_holder_info),
+            tran_dtime,
+        }
+    }
+}
+
+/// 계좌실명조회 API response body
+/// - api_tran_id: 거래고유번호(API)
+/// - api_tran_dtm: 거래일시(밀리세컨드)
+/// - rsp_code: 응답코드(API)(5자리)
+/// - rsp_message: 응답메시지(API)(300자리)
+/// - bank_tran_id: 거래고유번호(참가기관)
+/// - bank_tran_date: 거래일자(참가기관)
+/// - bank_code_tran: 응답코드를 부여한 참가기관.표준코드
+/// - bank_rsp_code: 응답코드(참가기관)(3자리)
+/// - bank_rsp_message: 응답메시지(참가기관)
+/// - bank_code_std: 개설기관.표준코드
+/// - bank_code_sub: 개설기관.점별코드
+/// - bank_name: 개설기관명
+/// - savings_bank_name: 개별저축은행명
+/// - account_num: 계좌번호
+/// - account_holder_info_type: 예금주 실명번호 구분코드
+/// - account_holder_info: 예금주 실명번호
+/// - account_holder_name: 예금주성명
+/// - account_type: 계좌종류("1": 수시입출금, "2": 예적금, "6": 수익증권, "T": 종합계좌)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseBody {
+    api_tran_id: String,
+    api_tran_dtm: ApiTranDtm,
+    rsp_code: ResponseCode,
+    rsp_message: String,
+    bank_tran_id: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    bank_tran_date: Option<Date>,
+    bank_code_tran: Option<String>,
+    bank_rsp_code: Option<BankResponseCode>,
+    bank_rsp_message: Option<String>,
+    bank_code_std: Option<String>,
+    bank_code_sub: Option<String>,
+    bank_name: Option<String>,
+    savings_bank_name: Option<String>,
+    account_num: Option<AccountNum>,
+    account_holder_info_type: Option<AccountHolderInfoType>,
+    account_holder_info: Option<Secret>,
+    account_holder_name: Option<String>,
+    account_type: Option<String>,
+}
+
+impl ResponseBody {
+    pub fn api_tran_id(&self) -> &str {
+        &self.api_tran_id
+    }
+
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+
+    pub fn bank_rsp_code(&self) -> Option<&BankResponseCode> {
+        self.bank_rsp_code.as_ref()
+    }
+
+    pub fn bank_rsp_message(&self) -> Option<&str> {
+        self.bank_rsp_message.as_deref()
+    }
+
+    pub fn account_holder_name(&self) -> Option<&str> {
+        self.account_holder_name.as_deref()
+    }
+}
@@ -0,0 +1,192 @@
+use super::{BankResponseCode, ResponseCode};
+use crate::types::{
+    datetime::{empty_as_none, ApiTranDtm, Date, TranDtime},
+    secret::AccountNum,
+    CntrAccountType, HttpMethod, TransferPurpose,
+};
+use serde::{Deserialize, Serialize};
+
+pub const METHOD: HttpMethod = HttpMethod::Post;
+
+/// 수취조회 API request body
+/// - bank_tran_id: 은행거래고유번호(이용기관코드 + "U" + 이용기관 부여번호 9자리)
+/// - cntr_account_type: 약정 계좌/계정 구분
+/// - cntr_account_num: 약정 계좌/계정 번호
+/// - bank_code_std: 수취(입금)기관.표준코드
+/// - account_num: 수취(입금) 계좌번호
+/// - print_content: 입금계좌인자내역
+/// - tran_amt: 거래금액
+/// - req_client_name: 요청고객성명
+/// - req_client_bank_code: 요청고객계좌 개설기관.표준코드
+/// - req_client_account_num: 요청고객계좌번호
+/// - req_client_num: 요청고객회원번호
+/// - transfer_purpose: 이체용도
+/// - tran_dtime: 요청일시(YYYYMMDDhhmmss)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RequestBody {
+    bank_tran_id: String,
+    cntr_account_type: CntrAccountType,
+    cntr_account_num: AccountNum,
+    bank_code_std: String,
+    account_num: AccountNum,
+    print_content: String,
+    tran_amt: String,
+    req_client_name: String,
+    req_client_bank_code: String,
+    req_client_account_num: AccountNum,
+    req_client_num: String,
+    transfer_purpose: TransferPurpose,
+    tran_dtime: TranDtime,
+}
+
+impl RequestBody {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        bank_tran_id: String,
+        cntr_account_type: CntrAccountType,
+        cntr_account_num: String,
+        bank_code_std: String,
+        account_num: String,
+        print_content: String,
+        tran_amt: String,
+        req_client_name: String,
+        req_client_bank_code: String,
+        req_client_account_num: String,
+        req_client_num: String,
+        transfer_purpose: TransferPurpose,
+        tran_dtime: TranDtime,
+    ) -> Self {
+        Self {
+            bank_tran_id,
+            cntr_account_type,
+            cntr_account_num: AccountNum::new(cntr_account_num),
+            bank_code_std,
+            account_num: AccountNum::new(account_num),
+            print_content,
+            tran_amt,
+            req_client_name,
+            req_client_bank_code,
+            req_client_account_num: AccountNum::new(req_client_account_num),
+            req_client_num,
+            transfer_purpose,
+            tran_dtime,
+        }
+    }
+}
+
+/// 수취조회 API response body
+/// - api_tran_id: 거래고유번호(API)
+/// - api_tran_dtm: 거래일시(밀리세컨드)
+/// - rsp_code: 응답코드(API)(5자리)
+/// - rsp_message: 응답메시지(API)(300자리)
+/// - bank_tran_id: 거래고유번호(참가기관)
+/// - bank_tran_date: 거래일자(참가기관)
+/// - bank_code_tran: 응답코드를 부여한 참가기관.표준코드
+/// - bank_rsp_code: 응답코드(참가기관)(3자리)
+/// - bank_rsp_message: 응답메시지(참가기관)
+/// - bank_code_std: 수취(입금)기관.표준코드
+/// - bank_code_sub: 수취(입금)기관.점별코드
+/// - bank_name: 수취(입금)기관명
+/// - savings_bank_name: 개별저축은행명
+/// - account_num: 수취(입금) 계좌번호
+/// - print_content: 입금계좌인자내역
+/// - account_holder_name: 수취인성명
+/// - tran_amt: 거래금액
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseBody {
+    api_tran_id: String,
+    api_tran_dtm: ApiTranDtm,
+    rsp_code: ResponseCode,
+    rsp_message: String,
+    bank_tran_id: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    bank_tran_date: Option<Date>,
+    bank_code_tran: Option<String>,
+    bank_rsp_code: Option<BankResponseCode>,
+    bank_rsp_message: Option<String>,
+    bank_code_std: Option<String>,
+    bank_code_sub: Option<String>,
+    bank_name: Option<String>,
+    savings_bank_name: Option<String>,
+    account_num: Option<AccountNum>,
+    print_content: Option<String>,
+    account_holder_name: Option<String>,
+    tran_amt: Option<String>,
+}
+
+impl ResponseBody {
+    pub fn api_tran_id(&self) -> &str {
+        &self.api_tran_id
+    }
+
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+
+    pub fn bank_rsp_code(&self) -> Option<&BankResponseCode> {
+        self.bank_rsp_code.as_ref()
+    }
+
+    pub fn bank_rsp_message(&self) -> Option<&str> {
+        self.bank_rsp_message.as_deref()
+    }
+
+    pub fn account_holder_name(&self) -> Option<&str> {
+        self.account_holder_name.as_deref()
+    }
+}
+
+/// 수취조회로 확인된 입금 대상
+/// - bank_tran_id: 수취조회 시 사용한 은행거래고유번호(입금이체의 recv_bank_tran_id)
+/// - bank_code_std: 수취(입금)기관.표준코드
+/// - account_num: 수취(입금) 계좌번호
+/// - account_holder_name: 수취인성명
+/// - print_content: 입금계좌인자내역
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifiedRecipient {
+    bank_tran_id: String,
+    bank_code_std: String,
+    account_num: AccountNum,
+    account_holder_name: String,
+    print_content: String,
+}
+
+impl VerifiedRecipient {
+    /// 수취조회 요청/응답으로부터 입금 대상을 만든다(수취조회가 성공하지 않은 경우 None)
+    pub fn from_inquiry(request: &RequestBody, response: &ResponseBody) -> Option<Self> {
+        if !response.rsp_code.is_success() {
+            return None;
+        }
+        Some(Self {
+            bank_tran_id: request.bank_tran_id.clone(),
+            bank_code_std: request.bank_code_std.clone(),
+            account_num: request.account_num.clone(),
+            account_holder_name: response.account_holder_name.clone()?,
+            print_content: request.print_content.clone(),
+        })
+    }
+
+    pub fn bank_tran_id(&self) -> &str {
+        &self.bank_tran_id
+    }
+
+    pub fn bank_code_std(&self) -> &str {
+        &self.bank_code_std
+    }
+
+    pub fn account_num(&self) -> &str {
+        self.account_num.as_str()
+    }
+
+    pub fn account_holder_name(&self) -> &str {
+        &self.account_holder_name
+    }
+
+    pub fn print_content(&self) -> &str {
+        &self.print_content
+    }
+}
@@ -0,0 +1,236 @@
+use super::{BankResponseCode, ResponseCode};
+use crate::types::{
+    datetime::{empty_as_none, ApiTranDtm, Date, Time, TranDtime},
+    secret::AccountNum,
+    CntrAccountType, HttpMethod, SortOrder,
+};
+use serde::{Deserialize, Serialize};
+
+pub const METHOD: HttpMethod = HttpMethod::Post;
+
+/// 송금인정보조회 API request body
+/// - bank_tran_id: 은행거래고유번호(이용기관코드 + "U" + 이용기관 부여번호 9자리)
+/// - cntr_account_type: 약정 계좌/계정 구분
+/// - cntr_account_num: 약정 계좌/계정 번호(입금내역을 조회할 모계좌)
+/// - from_date: 조회시작일자(YYYYMMDD)
+/// - from_time: 조회시작시간(hhmmss)
+/// - to_date: 조회종료일자(YYYYMMDD)
+/// - to_time: 조회종료시간(hhmmss)
+/// - sort_order: 정렬순서
+/// - tran_dtime: 요청일시(YYYYMMDDhhmmss)
+/// - befor_inquiry_trace_info: 직전조회추적정보(다음 페이지 조회 시 직전 응답의 값을 그대로 입력)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestBody {
+    bank_tran_id: String,
+    cntr_account_type: CntrAccountType,
+    cntr_account_num: AccountNum,
+    from_date: Date,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from_time: Option<Time>,
+    to_date: Date,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to_time: Option<Time>,
+    sort_order: SortOrder,
+    tran_dtime: TranDtime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    befor_inquiry_trace_info: Option<String>,
+}
+
+impl RequestBody {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        bank_tran_id: String,
+        cntr_account_type: CntrAccountType,
+        cntr_account_num: String,
+        from_date: Date,
+        from_time: Option<Time>,
+        to_date: Date,
+        to_time: Option<Time>,
+        sort_order: SortOrder,
+        tran_dtime: TranDtime,
+    ) -> Self {
+        Self {
+            bank_tran_id,
+            cntr_account_type,
+            cntr_account_num: AccountNum::new(cntr_account_num),
+            from_date,
+            from_time,
+            to_date,
+            to_time,
+            sort_order,
+            tran_dtime,
+            befor_inquiry_trace_info: None,
+        }
+    }
+
+    /// 직전 응답에 다음 페이지가 있는 경우 같은 조건의 다음 페이지 request body
+    /// - bank_tran_id: 다음 페이지 조회에 사용할 새 은행거래고유번호
+    /// - tran_dtime: 다음 페이지 조회 요청일시
+    pub fn next_page(
+        &self,
+        response: &ResponseBody,
+        bank_tran_id: String,
+        tran_dtime: TranDtime,
+    ) -> Option<Self> {
+        if !response.has_next_page() {
+            return None;
+        }
+        Some(Self {
+            bank_tran_id,
+            tran_dtime,
+            befor_inquiry_trace_info: response.befor_inquiry_trace_info.clone(),
+            ..self.clone()
+        })
+    }
+}
+
+/// 송금인정보조회 API response body
+/// - api_tran_id: 거래고유번호(API)
+/// - api_tran_dtm: 거래일시(밀리세컨드)
+/// - rsp_code: 응답코드(API)(5자리)
+/// - rsp_message: 응답메시지(API)(300자리)
+/// - bank_tran_id: 거래고유번호(참가기관)
+/// - bank_tran_date: 거래일자(참가기관)
+/// - bank_code_tran: 응답코드를 부여한 참가기관.표준코드
+/// - bank_rsp_code: 응답코드(참가기관)(3자리)
+/// - bank_rsp_message: 응답메시지(참가기관)
+/// - bank_name: 개설기관명
+/// - account_num_masked: 마스킹된 약정 계좌번호
+/// - next_page_yn: 다음페이지 존재여부("Y"/"N")
+/// - befor_inquiry_trace_info: 직전조회추적정보
+/// - res_cnt: 현재페이지 레코드건수
+/// - res_list: 송금인 목록
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseBody {
+    api_tran_id: String,
+    api_tran_dtm: ApiTranDtm,
+    rsp_code: ResponseCode,
+    rsp_message: String,
+    bank_tran_id: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    bank_tran_date: Option<Date>,
+    bank_code_tran: Option<String>,
+    bank_rsp_code: Option<BankResponseCode>,
+    bank_rsp_message: Option<String>,
+    bank_name: Option<String>,
+    account_num_masked: Option<String>,
+    next_page_yn: Option<String>,
+    befor_inquiry_trace_info: Option<String>,
+    res_cnt: Option<String>,
+    #[serde(default)]
+    res_list: Vec<Remitter>,
+}
+
+impl ResponseBody {
+    pub fn api_tran_id(&self) -> &str {
+        &self.api_tran_id
+    }
+
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+
+    pub fn bank_rsp_code(&self) -> Option<&BankResponseCode> {
+        self.bank_rsp_code.as_ref()
+    }
+
+    pub fn bank_rsp_message(&self) -> Option<&str> {
+        self.bank_rsp_message.as_deref()
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        self.next_page_yn.as_deref() == Some("Y")
+    }
+
+    pub fn res_list(&self) -> &[Remitter] {
+        &self.res_list
+    }
+}
+
+/// 송금인정보조회 API response의 송금인 정보
+/// - tran_date: 거래일자(YYYYMMDD)
+/// - tran_time: 거래시간(hhmmss)
+/// - print_content: 입금계좌인자내역(가상계좌 방식의 입금자 식별에 사용)
+/// - tran_amt: 거래금액
+/// - remitter_name: 송금인성명
+/// - remitter_bank_code_std: 송금인 계좌 개설기관.표준코드
+/// - remitter_bank_name: 송금인 계좌 개설기관명
+/// - remitter_account_num_masked: 마스킹된 송금인 계좌번호
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Remitter {
+    tran_date: Date,
+    tran_time: Time,
+    print_content: String,
+    tran_amt: String,
+    remitter_name: Option<String>,
+    remitter_bank_code_std: Option<String>,
+    remitter_bank_name: Option<String>,
+    remitter_account_num_masked: Option<String>,
+}
+
+impl Remitter {
+    pub fn tran_date(&self) -> Date {
+        self.tran_date
+    }
+
+    pub fn tran_time(&self) -> Time {
+        self.tran_time
+    }
+
+    pub fn print_content(&self) -> &str {
+        &self.print_content
+    }
+
+    pub fn tran_amt(&self) -> &str {
+        &self.tran_amt
+    }
+
+    pub fn remitter_name(&self) -> Option<&str> {
+        self.remitter_name.as_deref()
+    }
+
+    pub fn remitter_bank_code_std(&self) -> Option<&str> {
+        self.remitter_bank_code_std.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn body(from_time: Option<Time>, to_time: Option<Time>) -> serde_json::Value {
+        let date = Date::from(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        serde_json::to_value(RequestBody::new(
+            "M202300001U000000001".to_string(),
+            CntrAccountType::Account,
+            "9990001234".to_string(),
+            date,
+            from_time,
+            date,
+            to_time,
+            SortOrder::Ascending,
+            TranDtime::now(),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn omitted_time_range_is_left_out() {
+        let body = body(None, None);
+        assert!(body.get("from_time").is_none() && body.get("to_time").is_none());
+        assert!(body.get("befor_inquiry_trace_info").is_none());
+    }
+
+    #[test]
+    fn time_range_is_sent_as_hhmmss() {
+        let time = |hour| Time::from(NaiveTime::from_hms_opt(hour, 0, 0).unwrap());
+        let body = body(Some(time(9)), Some(time(18)));
+        assert_eq!(body["from_time"], "090000");
+        assert_eq!(body["to_time"], "180000");
+    }
+}
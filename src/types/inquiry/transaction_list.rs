@@ -0,0 +1,250 @@
+use super::{BankResponseCode, ResponseCode};
+use crate::types::{
+    datetime::{empty_as_none, ApiTranDtm, Date, Time, TranDtime},
+    HttpMethod, SortOrder,
+};
+use serde::{Deserialize, Serialize};
+
+pub const METHOD: HttpMethod = HttpMethod::Get;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InquiryType {
+    #[serde(rename = "A")]
+    All, // 전체
+    #[serde(rename = "I")]
+    Deposit, // 입금
+    #[serde(rename = "O")]
+    Withdraw, // 출금
+}
+
+/// 거래내역조회 API request query
+/// - bank_tran_id: 은행거래고유번호(이용기관코드 + "U" + 이용기관 부여번호 9자리)
+/// - fintech_use_num: 핀테크이용번호
+/// - inquiry_type: 조회구분코드
+/// - inquiry_base: 조회기준코드("D": 일자, "T": 시간)
+/// - from_date: 조회시작일자(YYYYMMDD)
+/// - from_time: 조회시작시간(hhmmss, inquiry_base가 "T"인 경우)
+/// - to_date: 조회종료일자(YYYYMMDD)
+/// - to_time: 조회종료시간(hhmmss, inquiry_base가 "T"인 경우)
+/// - sort_order: 정렬순서
+/// - tran_dtime: 요청일시(YYYYMMDDhhmmss)
+/// - befor_inquiry_trace_info: 직전조회추적정보(다음 페이지 조회 시 직전 응답의 값을 그대로 입력)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestBody {
+    bank_tran_id: String,
+    fintech_use_num: String,
+    inquiry_type: InquiryType,
+    inquiry_base: String,
+    from_date: Date,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from_time: Option<Time>,
+    to_date: Date,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to_time: Option<Time>,
+    sort_order: SortOrder,
+    tran_dtime: TranDtime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    befor_inquiry_trace_info: Option<String>,
+}
+
+impl RequestBody {
+    /// 일자 기준 조회
+    pub fn new(
+        bank_tran_id: String,
+        fintech_use_num: String,
+        inquiry_type: InquiryType,
+        from_date: Date,
+        to_date: Date,
+        sort_order: SortOrder,
+        tran_dtime: TranDtime,
+    ) -> Self {
+        Self {
+            bank_tran_id,
+            fintech_use_num,
+            inquiry_type,
+            inquiry_base: "D".to_string(),
+            from_date,
+            from_time: None,
+            to_date,
+            to_time: None,
+            sort_order,
+            tran_dtime,
+            befor_inquiry_trace_info: None,
+        }
+    }
+
+    /// 시간 기준 조회
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_time(
+        bank_tran_id: String,
+        fintech_use_num: String,
+        inquiry_type: InquiryType,
+        from_date: Date,
+        from_time: Time,
+        to_date: Date,
+        to_time: Time,
+        sort_order: SortOrder,
+        tran_dtime: TranDtime,
+    ) -> Self {
+        Self {
+            inquiry_base: "T".to_string(),
+            from_time: Some(from_time),
+            to_time: Some(to_time),
+            ..Self::new(
+                bank_tran_id,
+                fintech_use_num,
+                inquiry_type,
+                from_date,
+                to_date,
+                sort_order,
+                tran_dtime,
+            )
+        }
+    }
+
+    /// 직전 응답에 다음 페이지가 있는 경우 같은 조건의 다음 페이지 request query
+    /// - bank_tran_id: 다음 페이지 조회에 사용할 새 은행거래고유번호
+    /// - tran_dtime: 다음 페이지 조회 요청일시
+    pub fn next_page(
+        &self,
+        response: &ResponseBody,
+        bank_tran_id: String,
+        tran_dtime: TranDtime,
+    ) -> Option<Self> {
+        if !response.has_next_page() {
+            return None;
+        }
+        Some(Self {
+            bank_tran_id,
+            tran_dtime,
+            befor_inquiry_trace_info: response.befor_inquiry_trace_info.clone(),
+            ..self.clone()
+        })
+    }
+}
+
+/// 거래내역조회 API response body
+/// - api_tran_id: 거래고유번호(API)
+/// - api_tran_dtm: 거래일시(밀리세컨드)
+/// - rsp_code: 응답코드(API)(5자리)
+/// - rsp_message: 응답메시지(API)(300자리)
+/// - bank_tran_id: 거래고유번호(참가기관)
+/// - bank_tran_date: 거래일자(참가기관)
+/// - bank_code_tran: 응답코드를 부여한 참가기관.표준코드
+/// - bank_rsp_code: 응답코드(참가기관)(3자리)
+/// - bank_rsp_message: 응답메시지(참가기관)
+/// - bank_name: 개설기관명
+/// - savings_bank_name: 개별저축은행명
+/// - fintech_use_num: 핀테크이용번호
+/// - balance_amt: 계좌잔액(-금액 가능)
+/// - page_record_cnt: 현재페이지 레코드건수
+/// - next_page_yn: 다음페이지 존재여부("Y"/"N")
+/// - befor_inquiry_trace_info: 직전조회추적정보
+/// - res_list: 거래내역 목록
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseBody {
+    api_tran_id: String,
+    api_tran_dtm: ApiTranDtm,
+    rsp_code: ResponseCode,
+    rsp_message: String,
+    bank_tran_id: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    bank_tran_date: Option<Date>,
+    bank_code_tran: Option<String>,
+    bank_rsp_code: Option<BankResponseCode>,
+    bank_rsp_message: Option<String>,
+    bank_name: Option<String>,
+    savings_bank_name: Option<String>,
+    fintech_use_num: Option<String>,
+    balance_amt: Option<String>,
+    page_record_cnt: Option<String>,
+    next_page_yn: Option<String>,
+    befor_inquiry_trace_info: Option<String>,
+    #[serde(default)]
+    res_list: Vec<Transaction>,
+}
+
+impl ResponseBody {
+    pub fn api_tran_id(&self) -> &str {
+        &self.api_tran_id
+    }
+
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+
+    pub fn bank_rsp_code(&self) -> Option<&BankResponseCode> {
+        self.bank_rsp_code.as_ref()
+    }
+
+    pub fn bank_rsp_message(&self) -> Option<&str> {
+        self.bank_rsp_message.as_deref()
+    }
+
+    pub fn balance_amt(&self) -> Option<&str> {
+        self.balance_amt.as_deref()
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        self.next_page_yn.as_deref() == Some("Y")
+    }
+
+    pub fn res_list(&self) -> &[Transaction] {
+        &self.res_list
+    }
+}
+
+/// 거래내역
+/// - tran_date: 거래일자
+/// - tran_time: 거래시간
+/// - inout_type: 입출금구분("입금", "출금", "지급", "기타")
+/// - tran_type: 거래구분(현금, 대체, 급여 등)
+/// - print_content: 통장인자내용
+/// - tran_amt: 거래금액
+/// - after_balance_amt: 거래후잔액(-금액 가능)
+/// - branch_name: 거래점명
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Transaction {
+    tran_date: Date,
+    tran_time: Time,
+    inout_type: String,
+    tran_type: Option<String>,
+    print_content: String,
+    tran_amt: String,
+    after_balance_amt: String,
+    branch_name: Option<String>,
+}
+
+impl Transaction {
+    pub fn tran_date(&self) -> Date {
+        self.tran_date
+    }
+
+    pub fn tran_time(&self) -> Time {
+        self.tran_time
+    }
+
+    pub fn inout_type(&self) -> &str {
+        &self.inout_type
+    }
+
+    pub fn is_deposit(&self) -> bool {
+        self.inout_type == "입금"
+    }
+
+    pub fn print_content(&self) -> &str {
+        &self.print_content
+    }
+
+    pub fn tran_amt(&self) -> &str {
+        &self.tran_amt
+    }
+
+    pub fn after_balance_amt(&self) -> &str {
+        &self.after_balance_amt
+    }
+}
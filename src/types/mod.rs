@@ -1,6 +1,13 @@
-pub(crate) mod endpoint;
-pub(crate) mod oauth;
+pub mod datetime;
+pub mod endpoint;
+pub mod inquiry;
+pub mod oauth;
+pub mod response_code;
+pub mod secret;
+pub mod transfer;
+pub mod user;
 
+use secret::Secret;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::collections::HashMap;
@@ -9,7 +16,33 @@ pub trait GetHash {
     fn get_hash(&self) -> HashMap<&'static str, String>;
 }
 
+/// Access Token을 이용하는 API의 공통 헤더
+/// - access_token(Authorization): 오픈뱅킹에서 발급한 Access Token("Bearer" 방식)
 #[derive(Debug, Serialize, Deserialize)]
+pub struct AuthorizationHeader {
+    access_token: Secret,
+}
+
+impl AuthorizationHeader {
+    pub fn new(access_token: String) -> Self {
+        Self {
+            access_token: Secret::new(access_token),
+        }
+    }
+}
+
+impl GetHash for AuthorizationHeader {
+    fn get_hash(&self) -> HashMap<&'static str, String> {
+        let mut map = HashMap::new();
+        map.insert(
+            "authorization",
+            format!("Bearer {}", self.access_token.expose()),
+        );
+        map
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HttpMethod {
     Get,
     Post,
@@ -31,6 +64,8 @@ pub enum Scope {
     InsuInfo,
     #[serde(rename = "loaninfo")]
     LoanInfo,
+    #[serde(rename = "oob")]
+    Oob, // 이용기관 토큰(2-legged)
 }
 
 #[repr(i32)]
@@ -71,3 +106,39 @@ pub enum ClientDeviceType {
     #[serde(rename = "IO")]
     Ios,
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountHolderInfoType {
+    #[serde(rename = " ")]
+    BirthDate, // 생년월일(6자리)
+    #[serde(rename = "6")]
+    BusinessNumber, // 사업자등록번호(10자리)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CntrAccountType {
+    #[serde(rename = "N")]
+    Account, // 계좌
+    #[serde(rename = "C")]
+    Wallet, // 계정
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransferPurpose {
+    #[serde(rename = "TR")]
+    Remittance, // 송금
+    #[serde(rename = "ST")]
+    Settlement, // 결제
+    #[serde(rename = "RC")]
+    Charge, // 충전
+    #[serde(rename = "AU")]
+    Authentication, // 인증
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortOrder {
+    #[serde(rename = "D")]
+    Descending, // 최신순
+    #[serde(rename = "A")]
+    Ascending, // 과거순
+}
@@ -1,5 +1,7 @@
 use super::ResponseCode;
-use crate::types::{AuthType, ClientDeviceType, GetHash, HttpMethod, Lang, RegisterKind, Scope};
+use crate::types::{
+    secret::Secret, AuthType, ClientDeviceType, GetHash, HttpMethod, Lang, RegisterKind, Scope,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -12,25 +14,31 @@ pub const METHOD: HttpMethod = HttpMethod::Post;
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Header {
     user_seq_no: String,
-    user_connection_info: String,
-    access_token: String,
+    user_connection_info: Secret,
+    access_token: Secret,
 }
 
 impl Header {
     pub fn new(user_seq_no: String, user_connection_info: String, access_token: String) -> Self {
         Self {
             user_seq_no,
-            user_connection_info,
-            access_token,
+            user_connection_info: Secret::new(user_connection_info),
+            access_token: Secret::new(access_token),
         }
     }
 }
 impl GetHash for Header {
     fn get_hash(&self) -> HashMap<&'static str, String> {
         let mut map = HashMap::new();
-        map.insert("user_seq_no", self.user_seq_no.clone());
-        map.insert("user_connection_info", self.user_connection_info.clone());
-        map.insert("access_token", self.access_token.clone());
+        map.insert("kftc-bfop-userseqno", self.user_seq_no.clone());
+        map.insert(
+            "kftc-bfop-userci",
+            self.user_connection_info.expose().to_string(),
+        );
+        map.insert(
+            "kftc-bfop-accesstoken",
+            self.access_token.expose().to_string(),
+        );
         map
     }
 }
@@ -84,6 +92,7 @@ pub struct RequestBody {
 
 impl RequestBody {
     /// 사용자인증 API Request Body
+    #[allow(clippy::too_many_arguments)]
     pub fn new_authorize(
         client_id: String,
         redirect_uri: String,
@@ -132,6 +141,7 @@ impl RequestBody {
     }
 
     /// 서비스등록확인 API Request Body
+    #[allow(clippy::too_many_arguments)]
     pub fn new_authorize_account(
         client_id: String,
         redirect_uri: String,
@@ -184,3 +194,13 @@ pub struct ResponseBody {
     client_info: Option<String>,
     state: Option<String>,
 }
+
+impl ResponseBody {
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+}
@@ -1,5 +1,5 @@
 use super::ResponseCode;
-use crate::types::{HttpMethod, Scope};
+use crate::types::{secret::Secret, HttpMethod};
 use serde::{Deserialize, Serialize};
 
 pub const METHOD: HttpMethod = HttpMethod::Post;
@@ -11,16 +11,16 @@ pub const METHOD: HttpMethod = HttpMethod::Post;
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RequestBody {
     client_id: String,
-    client_secret: String,
-    access_token: String,
+    client_secret: Secret,
+    access_token: Secret,
 }
 
 impl RequestBody {
     pub fn new(client_id: String, client_secret: String, access_token: String) -> Self {
         Self {
             client_id,
-            client_secret,
-            access_token,
+            client_secret: Secret::new(client_secret),
+            access_token: Secret::new(access_token),
         }
     }
 }
@@ -37,7 +37,17 @@ pub struct ResponseBody {
     rsp_code: ResponseCode,
     rsp_message: String,
     client_id: Option<String>,
-    client_secret: Option<String>,
-    access_token: Option<String>,
-    refersh_token: Option<String>,
+    client_secret: Option<Secret>,
+    access_token: Option<Secret>,
+    refersh_token: Option<Secret>,
+}
+
+impl ResponseBody {
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
 }
@@ -1,22 +1,27 @@
 use super::ResponseCode;
-use crate::types::{HttpMethod, Scope};
+use crate::types::{secret::Secret, HttpMethod, Scope};
 use serde::{Deserialize, Serialize};
 
 pub const METHOD: HttpMethod = HttpMethod::Post;
 
 /// 토큰발급 API request body
-/// - code: 사용자인증 성공 후 획득한 Authorization Code
+/// - code: 사용자인증 성공 후 획득한 Authorization Code(3-legged)
 /// - client_id: 오픈뱅킹에서 발급한 이용기관 앱의 Client ID
 /// - client_secret: 오픈뱅킹에서 발급한 이용기관 앱의 Client Secret
-/// - redirect_uri: Access Token 을 전달받을 Callback URL
-/// - grant_type: 3-legged 인증을 위한 권한부여 방식 지정(고정값: "authorization_code")
+/// - redirect_uri: Access Token 을 전달받을 Callback URL(3-legged)
+/// - grant_type: 권한부여 방식("authorization_code": 3-legged, "client_credentials": 2-legged)
+/// - scope: Access Token 권한 범위(2-legged, 고정값: "oob")
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RequestBody {
-    code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<Secret>,
     client_id: String,
-    client_secret: String,
-    redirect_uri: String,
+    client_secret: Secret,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_uri: Option<String>,
     grant_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<Scope>,
 }
 
 impl RequestBody {
@@ -27,30 +32,78 @@ impl RequestBody {
         redirect_uri: String,
     ) -> Self {
         Self {
-            code,
+            code: Some(Secret::new(code)),
             client_id,
-            client_secret,
-            redirect_uri,
+            client_secret: Secret::new(client_secret),
+            redirect_uri: Some(redirect_uri),
             grant_type: "authorization_code".to_string(),
+            scope: None,
+        }
+    }
+
+    /// 이용기관 토큰(2-legged) 발급 요청
+    pub fn new_client_credentials(client_id: String, client_secret: String) -> Self {
+        Self {
+            code: None,
+            client_id,
+            client_secret: Secret::new(client_secret),
+            redirect_uri: None,
+            grant_type: "client_credentials".to_string(),
+            scope: Some(Scope::Oob),
         }
     }
 }
 
 /// 토큰발급 API response body
+/// - rsp_code: 응답코드(API)(오류 응답에만 포함)
+/// - rsp_message: 응답메시지(API)(오류 응답에만 포함)
 /// - access_token: 오픈뱅킹에서 발행된 Access Token
 /// - token_type: Access Token 유형(고정값: Bearer)
 /// - expires_in: Access Token 만료 기간(초)
 /// - refresh_token: Access Token 갱신 시 사용하는 Refresh Token
-/// - scope: Access Token 권한 범위(사용자인증 시 요청했던 권한 범위와 동일)
+/// - scope: Access Token 권한 범위(공백으로 구분, 사용자인증 시 요청했던 권한 범위와 동일)
 /// - user_seq_no: 사용자일련번호(10자리)
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResponseBody {
-    rsp_code: ResponseCode,
-    rsp_message: String,
-    access_token: Option<String>,
+    #[serde(default)]
+    rsp_code: Option<ResponseCode>,
+    #[serde(default)]
+    rsp_message: Option<String>,
+    access_token: Option<Secret>,
     token_type: Option<String>,
     expires_in: Option<u64>,
-    refresh_token: Option<String>,
-    scope: Option<Scope>,
+    refresh_token: Option<Secret>,
+    scope: Option<String>,
     user_seq_no: Option<String>,
 }
+
+impl ResponseBody {
+    /// 응답코드(API)(오류 응답에만 포함)
+    pub fn rsp_code(&self) -> Option<&ResponseCode> {
+        self.rsp_code.as_ref()
+    }
+
+    pub fn rsp_message(&self) -> Option<&str> {
+        self.rsp_message.as_deref()
+    }
+
+    pub fn access_token(&self) -> Option<&str> {
+        self.access_token.as_ref().map(Secret::expose)
+    }
+
+    pub fn expires_in(&self) -> Option<u64> {
+        self.expires_in
+    }
+
+    pub fn refresh_token(&self) -> Option<&str> {
+        self.refresh_token.as_ref().map(Secret::expose)
+    }
+
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    pub fn user_seq_no(&self) -> Option<&str> {
+        self.user_seq_no.as_deref()
+    }
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// 응답코드 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseCodeClass {
+    Success,           // 정상처리
+    Retryable,         // 일시적인 오류(잠시 후 재시도 가능)
+    UserError,         // 요청값/사용자 토큰 오류(요청을 고쳐야 함)
+    InstitutionConfig, // 이용기관 등록/설정/약정 오류
+    BankOutage,        // 참가기관 장애 또는 업무시간 외
+    OutcomeUnknown,    // 처리결과 미확인(이체결과조회 필요)
+    Unknown,           // 목록에 없는 응답코드
+}
+
+/// 응답코드 목록으로부터 enum과 코드/설명/분류 조회를 정의
+/// - 목록에 없는 코드는 Unknown(String)으로 역직렬화됨
+/// - 목록에 없는 코드의 분류는 ResponseCodeClass::Unknown
+macro_rules! response_codes {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident = $code:literal, $ko:literal, $en:literal, $class:ident;)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            Unknown(String),
+        }
+
+        impl $name {
+            pub fn code(&self) -> &str {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Unknown(code) => code,
+                }
+            }
+
+            pub fn description_ko(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $ko,)*
+                    Self::Unknown(_) => "알 수 없는 응답코드",
+                }
+            }
+
+            pub fn description_en(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $en,)*
+                    Self::Unknown(_) => "Unknown response code",
+                }
+            }
+
+            pub fn class(&self) -> ResponseCodeClass {
+                match self {
+                    $(Self::$variant => ResponseCodeClass::$class,)*
+                    Self::Unknown(_) => ResponseCodeClass::Unknown,
+                }
+            }
+
+            pub fn is_success(&self) -> bool {
+                self.class() == ResponseCodeClass::Success
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $($code => Self::$variant,)*
+                    code => Self::Unknown(code.to_string()),
+                })
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", self.code(), self.description_ko())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.code())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let code = String::deserialize(deserializer)?;
+                Ok(code.parse().unwrap_or_else(|e| match e {}))
+            }
+        }
+    };
+}
+
+response_codes! {
+    /// 응답코드(API)(5자리)
+    /// - O로 시작하는 코드: 사용자인증 및 API 게이트웨이 응답코드
+    /// - A로 시작하는 코드: 오픈뱅킹 API 처리 응답코드
+    pub enum ResponseCode {
+        Success = "O0000", "인증 성공", "Authentication succeeded", Success;
+        ParameterError = "O0001", "요청 파라미터 오류", "Invalid request parameter", UserError;
+        AccessTokenDenied = "O0002", "Access Token 거부", "Access token denied", UserError;
+        AccessTokenExpired = "O0003", "Access Token 만료", "Access token expired", UserError;
+        AccessDenied = "O0004", "API 호출 권한 없음", "Access denied", InstitutionConfig;
+        NotFound = "O0005", "존재하지 않는 API", "API not found", InstitutionConfig;
+        IPDisallowed = "O0006", "허용되지 않은 IP", "IP address not allowed", InstitutionConfig;
+        NoRoute = "O0007", "라우팅 경로 없음", "No route to API", InstitutionConfig;
+        AccountPlanLimitReached = "O0008", "계정 요금제 호출한도 초과", "Account plan call limit reached", InstitutionConfig;
+        ApiPlanLimitReached = "O0009", "API 요금제 호출한도 초과", "API plan call limit reached", InstitutionConfig;
+        WrongMethod = "O0010", "허용되지 않은 HTTP Method", "HTTP method not allowed", InstitutionConfig;
+        WrongScope = "O0011", "허용되지 않은 scope", "Scope not allowed", InstitutionConfig;
+        DenialOfService = "O0012", "서비스 거부(일시적 과부하)", "Service temporarily denied", Retryable;
+        TooMuchCall = "O0013", "초당 호출한도 초과", "Too many calls per second", Retryable;
+        RefreshTokenDenied = "O0014", "Refresh Token 거부", "Refresh token denied", UserError;
+        RefreshTokenExpired = "O0015", "Refresh Token 만료", "Refresh token expired", UserError;
+        ApiSuccess = "A0000", "처리 성공", "Processed successfully", Success;
+        ProcessingError = "A0001", "처리 중 오류 발생", "Error while processing", Retryable;
+        BankError = "A0002", "참가기관 오류(참가기관 응답코드 확인)", "Participant bank error (see bank_rsp_code)", BankOutage;
+        BankResponseFormatError = "A0003", "참가기관 응답 전문 오류", "Malformed participant bank response", BankOutage;
+        RequestFormatError = "A0004", "요청 전문 포맷 오류", "Malformed request", UserError;
+        BankTimeout = "A0007", "참가기관 응답 지연(처리결과 미확인)", "Participant bank timed out (outcome unknown)", OutcomeUnknown;
+        DuplicateBankTranId = "A0019", "은행거래고유번호 중복", "Duplicate bank_tran_id", InstitutionConfig;
+        SystemError = "A0999", "기타 시스템 오류", "Other system error", Retryable;
+    }
+}
+
+response_codes! {
+    /// 응답코드(참가기관)(3자리)
+    /// - 목록에 없는 코드는 Unknown으로 분류되며, 이체에서는 처리결과를 알 수 없는 것으로 보아 이체결과조회가 필요함
+    pub enum BankResponseCode {
+        Success = "000", "정상처리", "Processed normally", Success;
+        Processing = "400", "처리중(처리결과 미확인)", "Processing (outcome unknown)", OutcomeUnknown;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listed_codes_keep_their_class() {
+        assert_eq!(
+            "000".parse::<BankResponseCode>().unwrap().class(),
+            ResponseCodeClass::Success
+        );
+        assert_eq!(
+            "400".parse::<BankResponseCode>().unwrap().class(),
+            ResponseCodeClass::OutcomeUnknown
+        );
+        assert_eq!(
+            "A0007".parse::<ResponseCode>().unwrap().class(),
+            ResponseCodeClass::OutcomeUnknown
+        );
+    }
+
+    #[test]
+    fn unlisted_codes_are_unknown() {
+        for code in ["311", "413", "551", "999"] {
+            let bank_rsp_code = code.parse::<BankResponseCode>().unwrap();
+            assert_eq!(bank_rsp_code, BankResponseCode::Unknown(code.to_string()));
+            assert_eq!(bank_rsp_code.class(), ResponseCodeClass::Unknown);
+        }
+        assert_eq!(
+            "A9999".parse::<ResponseCode>().unwrap().class(),
+            ResponseCodeClass::Unknown
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! 로그에 남기면 안 되는 값
+//! - Secret: Client Secret, Access Token, 사용자 CI 등(Debug/Display 출력 시 가려지고, drop 시 메모리를 0으로 덮어씀)
+//! - AccountNum: 계좌번호(Debug 출력 시 오픈뱅킹의 account_num_masked와 같이 끝 3자리를 가림)
+use crate::api::redact::REDACTED;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use zeroize::Zeroize;
+
+/// 비밀정보(직렬화 시에는 원래 값 그대로 전송)
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: String) -> Self {
+        Self(value)
+    }
+
+    /// 가려지지 않은 원래 값
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// 계좌번호(직렬화 시에는 원래 값 그대로 전송)
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountNum(String);
+
+impl AccountNum {
+    pub fn new(value: String) -> Self {
+        Self(value)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 오픈뱅킹 account_num_masked 형식으로 가린 계좌번호
+    pub fn masked(&self) -> String {
+        mask_account_num(&self.0)
+    }
+}
+
+impl From<String> for AccountNum {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Debug for AccountNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.masked())
+    }
+}
+
+impl fmt::Display for AccountNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.masked())
+    }
+}
+
+/// 계좌번호의 끝 3자리를 "***"로 가림(예: "1234567890" -> "1234567***")
+pub fn mask_account_num(account_num: &str) -> String {
+    let visible = account_num
+        .char_indices()
+        .rev()
+        .nth(2)
+        .map_or(0, |(index, _)| index);
+    format!("{}***", &account_num[..visible])
+}
@@ -0,0 +1,384 @@
+use super::{is_outcome_unknown, BankResponseCode, ResponseCode};
+use crate::types::{
+    datetime::{empty_as_none, ApiTranDtm, Date, TranDtime},
+    endpoint::TransferEndpoint,
+    inquiry::receive::VerifiedRecipient,
+    secret::{AccountNum, Secret},
+    CntrAccountType, HttpMethod, TransferPurpose,
+};
+use crate::ApiError;
+use serde::{Deserialize, Serialize};
+
+pub const METHOD: HttpMethod = HttpMethod::Post;
+
+/// 입금이체 1회 요청 시 req_list에 담을 수 있는 최대 건수
+pub const MAX_REQ_CNT: usize = 10;
+
+/// 입금이체 API request body
+/// - cntr_account_type: 약정 계좌/계정 구분
+/// - cntr_account_num: 약정 계좌/계정 번호(출금될 이용기관 계좌)
+/// - wd_pass_phrase: 입금이체용 암호문구(테스트 환경은 "NONE")
+/// - wd_print_content: 출금계좌인자내역
+/// - name_check_option: 수취인성명 검증 여부("on"/"off")
+/// - tran_dtime: 요청일시(YYYYMMDDhhmmss)
+/// - req_cnt: 입금요청건수
+/// - req_list: 입금요청 목록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestBody {
+    cntr_account_type: CntrAccountType,
+    cntr_account_num: AccountNum,
+    wd_pass_phrase: Secret,
+    wd_print_content: String,
+    name_check_option: String,
+    tran_dtime: TranDtime,
+    req_cnt: String,
+    req_list: Vec<RequestItem>,
+}
+
+impl RequestBody {
+    /// 입금이체 API Request Body(req_list의 tran_no는 순서대로 부여됨)
+    /// - req_list는 1건 이상 MAX_REQ_CNT건 이하이고 모두 같은 방식(핀테크이용번호/계좌번호)이어야 함(아니면 ApiError::InvalidRequest)
+    pub fn new(
+        cntr_account_type: CntrAccountType,
+        cntr_account_num: String,
+        wd_pass_phrase: String,
+        wd_print_content: String,
+        name_check: bool,
+        tran_dtime: TranDtime,
+        req_list: Vec<RequestItem>,
+    ) -> Result<Self, ApiError> {
+        if req_list.is_empty() || req_list.len() > MAX_REQ_CNT {
+            return Err(ApiError::InvalidRequest(format!(
+                "deposit req_list must have 1 to {} items, got {}",
+                MAX_REQ_CNT,
+                req_list.len()
+            )));
+        }
+        let fin_num = req_list[0].fintech_use_num.is_some();
+        if req_list
+            .iter()
+            .any(|item| item.fintech_use_num.is_some() != fin_num)
+        {
+            return Err(ApiError::InvalidRequest(
+                "deposit req_list must not mix fintech_use_num and account_num items".to_string(),
+            ));
+        }
+        Ok(Self::build(
+            cntr_account_type,
+            cntr_account_num,
+            wd_pass_phrase,
+            wd_print_content,
+            name_check,
+            tran_dtime,
+            req_list,
+        ))
+    }
+
+    fn build(
+        cntr_account_type: CntrAccountType,
+        cntr_account_num: String,
+        wd_pass_phrase: String,
+        wd_print_content: String,
+        name_check: bool,
+        tran_dtime: TranDtime,
+        mut req_list: Vec<RequestItem>,
+    ) -> Self {
+        for (i, item) in req_list.iter_mut().enumerate() {
+            item.tran_no = (i + 1).to_string();
+        }
+        Self {
+            cntr_account_type,
+            cntr_account_num: AccountNum::new(cntr_account_num),
+            wd_pass_phrase: Secret::new(wd_pass_phrase),
+            wd_print_content,
+            name_check_option: if name_check { "on" } else { "off" }.to_string(),
+            tran_dtime,
+            req_cnt: req_list.len().to_string(),
+            req_list,
+        }
+    }
+
+    /// 대량의 입금요청을 API 규격에 맞는 여러 request body로 나눔
+    /// - 핀테크이용번호/계좌번호 방식별로 묶은 뒤 max_req_cnt(최대 MAX_REQ_CNT)건씩 나눔
+    #[allow(clippy::too_many_arguments)]
+    pub fn batches(
+        cntr_account_type: CntrAccountType,
+        cntr_account_num: String,
+        wd_pass_phrase: String,
+        wd_print_content: String,
+        name_check: bool,
+        tran_dtime: TranDtime,
+        req_list: Vec<RequestItem>,
+        max_req_cnt: usize,
+    ) -> Vec<Self> {
+        let max_req_cnt = max_req_cnt.clamp(1, MAX_REQ_CNT);
+        let (fin_num, acnt_num): (Vec<_>, Vec<_>) = req_list
+            .into_iter()
+            .partition(|item| item.fintech_use_num.is_some());
+        [fin_num, acnt_num]
+            .into_iter()
+            .flat_map(|items| {
+                items
+                    .chunks(max_req_cnt)
+                    .map(|chunk| chunk.to_vec())
+                    .collect::<Vec<_>>()
+            })
+            .map(|chunk| {
+                Self::build(
+                    cntr_account_type.clone(),
+                    cntr_account_num.clone(),
+                    wd_pass_phrase.clone(),
+                    wd_print_content.clone(),
+                    name_check,
+                    tran_dtime,
+                    chunk,
+                )
+            })
+            .collect()
+    }
+
+    /// 입금계좌 지정 방식에 따른 API endpoint
+    pub fn endpoint(&self) -> TransferEndpoint {
+        match self.req_list.first() {
+            Some(item) if item.fintech_use_num.is_none() => TransferEndpoint::DepositAcntNum,
+            _ => TransferEndpoint::DepositFinNum,
+        }
+    }
+
+    pub fn req_list(&self) -> &[RequestItem] {
+        &self.req_list
+    }
+}
+
+/// 입금이체 API request body의 입금요청
+/// - tran_no: 거래순번(RequestBody 생성 시 부여)
+/// - bank_tran_id: 은행거래고유번호(이용기관코드 + "U" + 이용기관 부여번호 9자리)
+/// - fintech_use_num: (핀테크이용번호 사용 시) 입금계좌 핀테크이용번호
+/// - bank_code_std: (계좌번호 사용 시) 입금계좌 개설기관.표준코드
+/// - account_num: (계좌번호 사용 시) 입금계좌번호
+/// - account_holder_name: (계좌번호 사용 시) 수취인성명
+/// - print_content: 입금계좌인자내역
+/// - tran_amt: 거래금액
+/// - req_client_name: 요청고객성명
+/// - req_client_bank_code: 요청고객계좌 개설기관.표준코드
+/// - req_client_account_num: 요청고객계좌번호
+/// - req_client_num: 요청고객회원번호
+/// - transfer_purpose: 이체용도
+/// - recv_bank_tran_id: 수취조회 시 사용한 은행거래고유번호
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestItem {
+    tran_no: String,
+    bank_tran_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fintech_use_num: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank_code_std: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_num: Option<AccountNum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_holder_name: Option<String>,
+    print_content: String,
+    tran_amt: String,
+    req_client_name: String,
+    req_client_bank_code: String,
+    req_client_account_num: AccountNum,
+    req_client_num: String,
+    transfer_purpose: TransferPurpose,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_bank_tran_id: Option<String>,
+}
+
+impl RequestItem {
+    /// 입금요청(핀테크이용번호 사용)
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_fin_num(
+        bank_tran_id: String,
+        fintech_use_num: String,
+        print_content: String,
+        tran_amt: String,
+        req_client_name: String,
+        req_client_bank_code: String,
+        req_client_account_num: String,
+        req_client_num: String,
+        transfer_purpose: TransferPurpose,
+    ) -> Self {
+        Self {
+            tran_no: String::new(),
+            bank_tran_id,
+            fintech_use_num: Some(fintech_use_num),
+            bank_code_std: None,
+            account_num: None,
+            account_holder_name: None,
+            print_content,
+            tran_amt,
+            req_client_name,
+            req_client_bank_code,
+            req_client_account_num: AccountNum::new(req_client_account_num),
+            req_client_num,
+            transfer_purpose,
+            recv_bank_tran_id: None,
+        }
+    }
+
+    /// 입금요청(계좌번호 사용, 수취조회로 확인된 수취인)
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_acnt_num(
+        bank_tran_id: String,
+        recipient: &VerifiedRecipient,
+        tran_amt: String,
+        req_client_name: String,
+        req_client_bank_code: String,
+        req_client_account_num: String,
+        req_client_num: String,
+        transfer_purpose: TransferPurpose,
+    ) -> Self {
+        Self {
+            tran_no: String::new(),
+            bank_tran_id,
+            fintech_use_num: None,
+            bank_code_std: Some(recipient.bank_code_std().to_string()),
+            account_num: Some(AccountNum::new(recipient.account_num().to_string())),
+            account_holder_name: Some(recipient.account_holder_name().to_string()),
+            print_content: recipient.print_content().to_string(),
+            tran_amt,
+            req_client_name,
+            req_client_bank_code,
+            req_client_account_num: AccountNum::new(req_client_account_num),
+            req_client_num,
+            transfer_purpose,
+            recv_bank_tran_id: Some(recipient.bank_tran_id().to_string()),
+        }
+    }
+
+    pub fn bank_tran_id(&self) -> &str {
+        &self.bank_tran_id
+    }
+
+    pub fn tran_amt(&self) -> &str {
+        &self.tran_amt
+    }
+}
+
+/// 입금이체 API response body
+/// - api_tran_id: 거래고유번호(API)
+/// - api_tran_dtm: 거래일시(밀리세컨드)
+/// - rsp_code: 응답코드(API)(5자리)
+/// - rsp_message: 응답메시지(API)(300자리)
+/// - wd_bank_code_std: 출금기관.표준코드
+/// - wd_bank_code_sub: 출금기관.점별코드
+/// - wd_bank_name: 출금기관명
+/// - wd_account_num_masked: 마스킹된 출금계좌번호
+/// - wd_print_content: 출금계좌인자내역
+/// - wd_account_holder_name: 송금인성명
+/// - res_cnt: 입금처리건수
+/// - res_list: 입금처리 결과 목록
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseBody {
+    api_tran_id: String,
+    api_tran_dtm: ApiTranDtm,
+    rsp_code: ResponseCode,
+    rsp_message: String,
+    wd_bank_code_std: Option<String>,
+    wd_bank_code_sub: Option<String>,
+    wd_bank_name: Option<String>,
+    wd_account_num_masked: Option<String>,
+    wd_print_content: Option<String>,
+    wd_account_holder_name: Option<String>,
+    res_cnt: Option<String>,
+    #[serde(default)]
+    res_list: Vec<ResponseItem>,
+}
+
+impl ResponseBody {
+    pub fn api_tran_id(&self) -> &str {
+        &self.api_tran_id
+    }
+
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+
+    pub fn res_list(&self) -> &[ResponseItem] {
+        &self.res_list
+    }
+
+    pub fn into_res_list(self) -> Vec<ResponseItem> {
+        self.res_list
+    }
+}
+
+/// 입금이체 API response body의 입금처리 결과
+/// - tran_no: 거래순번
+/// - bank_tran_id: 거래고유번호(참가기관)
+/// - bank_tran_date: 거래일자(참가기관)
+/// - bank_code_tran: 응답코드를 부여한 참가기관.표준코드
+/// - bank_rsp_code: 응답코드(참가기관)(3자리)
+/// - bank_rsp_message: 응답메시지(참가기관)
+/// - fintech_use_num: 입금계좌 핀테크이용번호
+/// - account_alias: 입금계좌별명
+/// - bank_code_std: 입금기관.표준코드
+/// - bank_code_sub: 입금기관.점별코드
+/// - bank_name: 입금기관명
+/// - savings_bank_name: 개별저축은행명
+/// - account_num_masked: 마스킹된 입금계좌번호
+/// - print_content: 입금계좌인자내역
+/// - account_holder_name: 수취인성명
+/// - tran_amt: 거래금액
+/// - withdraw_bank_tran_id: 출금 거래고유번호(참가기관)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseItem {
+    tran_no: String,
+    bank_tran_id: String,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    bank_tran_date: Option<Date>,
+    bank_code_tran: Option<String>,
+    bank_rsp_code: BankResponseCode,
+    bank_rsp_message: Option<String>,
+    fintech_use_num: Option<String>,
+    account_alias: Option<String>,
+    bank_code_std: Option<String>,
+    bank_code_sub: Option<String>,
+    bank_name: Option<String>,
+    savings_bank_name: Option<String>,
+    account_num_masked: Option<String>,
+    print_content: Option<String>,
+    account_holder_name: Option<String>,
+    tran_amt: Option<String>,
+    withdraw_bank_tran_id: Option<String>,
+}
+
+impl ResponseItem {
+    pub fn tran_no(&self) -> &str {
+        &self.tran_no
+    }
+
+    pub fn bank_tran_id(&self) -> &str {
+        &self.bank_tran_id
+    }
+
+    pub fn bank_rsp_code(&self) -> &BankResponseCode {
+        &self.bank_rsp_code
+    }
+
+    pub fn bank_rsp_message(&self) -> Option<&str> {
+        self.bank_rsp_message.as_deref()
+    }
+
+    pub fn tran_amt(&self) -> Option<&str> {
+        self.tran_amt.as_deref()
+    }
+
+    /// 참가기관 응답코드가 정상처리인지 여부
+    pub fn is_success(&self) -> bool {
+        self.bank_rsp_code.is_success()
+    }
+
+    /// 처리결과를 알 수 없어 이체결과조회가 필요한지 여부
+    pub fn is_outcome_unknown(&self) -> bool {
+        is_outcome_unknown(&ResponseCode::ApiSuccess, Some(&self.bank_rsp_code))
+    }
+}
@@ -0,0 +1,20 @@
+pub mod deposit;
+pub mod result;
+pub mod withdraw;
+
+pub use super::response_code::{BankResponseCode, ResponseCode, ResponseCodeClass};
+
+/// API/참가기관 응답코드로 보아 이체 처리결과를 알 수 없어 이체결과조회가 필요한지 여부
+/// - 목록에 없는 응답코드(ResponseCodeClass::Unknown)는 성공/실패를 단정하지 않고 처리결과 미확인으로 봄
+pub fn is_outcome_unknown(
+    rsp_code: &ResponseCode,
+    bank_rsp_code: Option<&BankResponseCode>,
+) -> bool {
+    let unresolved = |class| {
+        matches!(
+            class,
+            ResponseCodeClass::OutcomeUnknown | ResponseCodeClass::Unknown
+        )
+    };
+    unresolved(rsp_code.class()) || bank_rsp_code.is_some_and(|code| unresolved(code.class()))
+}
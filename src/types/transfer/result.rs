@@ -0,0 +1,177 @@
+use super::{is_outcome_unknown, BankResponseCode, ResponseCode};
+use crate::types::{
+    datetime::{empty_as_none, ApiTranDtm, Date, TranDtime},
+    HttpMethod,
+};
+use serde::{Deserialize, Serialize};
+
+pub const METHOD: HttpMethod = HttpMethod::Post;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CheckType {
+    #[serde(rename = "1")]
+    Withdraw, // 출금이체
+    #[serde(rename = "2")]
+    Deposit, // 입금이체
+}
+
+/// 이체결과조회 API request body
+/// - check_type: 조회구분
+/// - tran_dtime: 요청일시(YYYYMMDDhhmmss)
+/// - req_cnt: 요청건수
+/// - req_list: 조회요청 목록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestBody {
+    check_type: CheckType,
+    tran_dtime: TranDtime,
+    req_cnt: String,
+    req_list: Vec<RequestItem>,
+}
+
+impl RequestBody {
+    /// 이체결과조회 API Request Body(req_list의 tran_no는 순서대로 부여됨)
+    pub fn new(
+        check_type: CheckType,
+        tran_dtime: TranDtime,
+        mut req_list: Vec<RequestItem>,
+    ) -> Self {
+        for (i, item) in req_list.iter_mut().enumerate() {
+            item.tran_no = (i + 1).to_string();
+        }
+        Self {
+            check_type,
+            tran_dtime,
+            req_cnt: req_list.len().to_string(),
+            req_list,
+        }
+    }
+}
+
+/// 이체결과조회 API request body의 조회요청
+/// - tran_no: 거래순번(RequestBody 생성 시 부여)
+/// - org_bank_tran_id: 원거래 은행거래고유번호
+/// - org_bank_tran_date: 원거래 거래일자(YYYYMMDD)
+/// - org_tran_amt: 원거래 거래금액
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestItem {
+    tran_no: String,
+    org_bank_tran_id: String,
+    org_bank_tran_date: Date,
+    org_tran_amt: String,
+}
+
+impl RequestItem {
+    pub fn new(org_bank_tran_id: String, org_bank_tran_date: Date, org_tran_amt: String) -> Self {
+        Self {
+            tran_no: String::new(),
+            org_bank_tran_id,
+            org_bank_tran_date,
+            org_tran_amt,
+        }
+    }
+
+    pub fn org_bank_tran_id(&self) -> &str {
+        &self.org_bank_tran_id
+    }
+}
+
+/// 이체결과조회 API response body
+/// - api_tran_id: 거래고유번호(API)
+/// - api_tran_dtm: 거래일시(밀리세컨드)
+/// - rsp_code: 응답코드(API)(5자리)
+/// - rsp_message: 응답메시지(API)(300자리)
+/// - res_cnt: 처리건수
+/// - res_list: 조회결과 목록
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseBody {
+    api_tran_id: String,
+    api_tran_dtm: ApiTranDtm,
+    rsp_code: ResponseCode,
+    rsp_message: String,
+    res_cnt: Option<String>,
+    #[serde(default)]
+    res_list: Vec<ResponseItem>,
+}
+
+impl ResponseBody {
+    pub fn api_tran_id(&self) -> &str {
+        &self.api_tran_id
+    }
+
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+
+    pub fn res_list(&self) -> &[ResponseItem] {
+        &self.res_list
+    }
+
+    pub fn into_res_list(self) -> Vec<ResponseItem> {
+        self.res_list
+    }
+}
+
+/// 이체결과조회 API response body의 조회결과
+/// - tran_no: 거래순번
+/// - check_type: 조회구분
+/// - org_bank_tran_id: 원거래 은행거래고유번호
+/// - org_bank_tran_date: 원거래 거래일자
+/// - org_tran_amt: 원거래 거래금액
+/// - bank_tran_id: 거래고유번호(참가기관)
+/// - bank_tran_date: 거래일자(참가기관)
+/// - bank_code_tran: 응답코드를 부여한 참가기관.표준코드
+/// - bank_rsp_code: 원거래의 응답코드(참가기관)(3자리)
+/// - bank_rsp_message: 원거래의 응답메시지(참가기관)
+/// - bank_code_std: 출금(입금)기관.표준코드
+/// - bank_name: 출금(입금)기관명
+/// - account_num_masked: 마스킹된 출금(입금)계좌번호
+/// - account_holder_name: 출금(입금)계좌 예금주성명
+/// - tran_amt: 거래금액
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseItem {
+    tran_no: String,
+    check_type: Option<CheckType>,
+    org_bank_tran_id: String,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    org_bank_tran_date: Option<Date>,
+    org_tran_amt: Option<String>,
+    bank_tran_id: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    bank_tran_date: Option<Date>,
+    bank_code_tran: Option<String>,
+    bank_rsp_code: BankResponseCode,
+    bank_rsp_message: Option<String>,
+    bank_code_std: Option<String>,
+    bank_name: Option<String>,
+    account_num_masked: Option<String>,
+    account_holder_name: Option<String>,
+    tran_amt: Option<String>,
+}
+
+impl ResponseItem {
+    pub fn org_bank_tran_id(&self) -> &str {
+        &self.org_bank_tran_id
+    }
+
+    pub fn bank_rsp_code(&self) -> &BankResponseCode {
+        &self.bank_rsp_code
+    }
+
+    pub fn bank_rsp_message(&self) -> Option<&str> {
+        self.bank_rsp_message.as_deref()
+    }
+
+    /// 원거래가 정상처리되었는지 여부
+    pub fn is_success(&self) -> bool {
+        self.bank_rsp_code.is_success()
+    }
+
+    /// 원거래의 처리결과가 확정되었는지 여부
+    pub fn is_final(&self) -> bool {
+        !is_outcome_unknown(&ResponseCode::ApiSuccess, Some(&self.bank_rsp_code))
+    }
+}
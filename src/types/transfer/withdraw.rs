@@ -0,0 +1,254 @@
+use super::{is_outcome_unknown, BankResponseCode, ResponseCode};
+use crate::types::{
+    datetime::{empty_as_none, ApiTranDtm, Date, TranDtime},
+    endpoint::TransferEndpoint,
+    secret::AccountNum,
+    CntrAccountType, HttpMethod, TransferPurpose,
+};
+use serde::{Deserialize, Serialize};
+
+pub const METHOD: HttpMethod = HttpMethod::Post;
+
+/// 출금이체 API request body
+/// - bank_tran_id: 은행거래고유번호(이용기관코드 + "U" + 이용기관 부여번호 9자리)
+/// - cntr_account_type: 약정 계좌/계정 구분
+/// - cntr_account_num: 약정 계좌/계정 번호(출금된 금액이 입금될 이용기관 계좌)
+/// - dps_print_content: 입금계좌인자내역
+/// - fintech_use_num: (핀테크이용번호 사용 시) 출금계좌 핀테크이용번호
+/// - bank_code_std: (계좌번호 사용 시) 출금계좌 개설기관.표준코드
+/// - account_num: (계좌번호 사용 시) 출금계좌번호
+/// - user_seq_no: (계좌번호 사용 시) 사용자일련번호
+/// - tran_amt: 거래금액
+/// - tran_dtime: 요청일시(YYYYMMDDhhmmss)
+/// - req_client_name: 요청고객성명
+/// - req_client_bank_code: (계좌번호 사용 시) 요청고객계좌 개설기관.표준코드
+/// - req_client_account_num: (계좌번호 사용 시) 요청고객계좌번호
+/// - req_client_fintech_use_num: (핀테크이용번호 사용 시) 요청고객 핀테크이용번호
+/// - req_client_num: 요청고객회원번호
+/// - transfer_purpose: 이체용도
+/// - recv_client_name: 최종수취고객성명
+/// - recv_client_bank_code: 최종수취고객계좌 개설기관.표준코드
+/// - recv_client_account_num: 최종수취고객계좌번호
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RequestBody {
+    bank_tran_id: String,
+    cntr_account_type: CntrAccountType,
+    cntr_account_num: AccountNum,
+    dps_print_content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fintech_use_num: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank_code_std: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_num: Option<AccountNum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_seq_no: Option<String>,
+    tran_amt: String,
+    tran_dtime: TranDtime,
+    req_client_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    req_client_bank_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    req_client_account_num: Option<AccountNum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    req_client_fintech_use_num: Option<String>,
+    req_client_num: String,
+    transfer_purpose: TransferPurpose,
+    recv_client_name: String,
+    recv_client_bank_code: String,
+    recv_client_account_num: AccountNum,
+}
+
+impl RequestBody {
+    /// 출금이체(핀테크이용번호 사용) API Request Body
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_fin_num(
+        bank_tran_id: String,
+        cntr_account_type: CntrAccountType,
+        cntr_account_num: String,
+        dps_print_content: String,
+        fintech_use_num: String,
+        tran_amt: String,
+        tran_dtime: TranDtime,
+        req_client_name: String,
+        req_client_fintech_use_num: String,
+        req_client_num: String,
+        transfer_purpose: TransferPurpose,
+        recv_client_name: String,
+        recv_client_bank_code: String,
+        recv_client_account_num: String,
+    ) -> Self {
+        Self {
+            bank_tran_id,
+            cntr_account_type,
+            cntr_account_num: AccountNum::new(cntr_account_num),
+            dps_print_content,
+            fintech_use_num: Some(fintech_use_num),
+            bank_code_std: None,
+            account_num: None,
+            user_seq_no: None,
+            tran_amt,
+            tran_dtime,
+            req_client_name,
+            req_client_bank_code: None,
+            req_client_account_num: None,
+            req_client_fintech_use_num: Some(req_client_fintech_use_num),
+            req_client_num,
+            transfer_purpose,
+            recv_client_name,
+            recv_client_bank_code,
+            recv_client_account_num: AccountNum::new(recv_client_account_num),
+        }
+    }
+
+    /// 출금이체(계좌번호 사용) API Request Body
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_acnt_num(
+        bank_tran_id: String,
+        cntr_account_type: CntrAccountType,
+        cntr_account_num: String,
+        dps_print_content: String,
+        bank_code_std: String,
+        account_num: String,
+        user_seq_no: String,
+        tran_amt: String,
+        tran_dtime: TranDtime,
+        req_client_name: String,
+        req_client_bank_code: String,
+        req_client_account_num: String,
+        req_client_num: String,
+        transfer_purpose: TransferPurpose,
+        recv_client_name: String,
+        recv_client_bank_code: String,
+        recv_client_account_num: String,
+    ) -> Self {
+        Self {
+            bank_tran_id,
+            cntr_account_type,
+            cntr_account_num: AccountNum::new(cntr_account_num),
+            dps_print_content,
+            fintech_use_num: None,
+            bank_code_std: Some(bank_code_std),
+            account_num: Some(AccountNum::new(account_num)),
+            user_seq_no: Some(user_seq_no),
+            tran_amt,
+            tran_dtime,
+            req_client_name,
+            req_client_bank_code: Some(req_client_bank_code),
+            req_client_account_num: Some(AccountNum::new(req_client_account_num)),
+            req_client_fintech_use_num: None,
+            req_client_num,
+            transfer_purpose,
+            recv_client_name,
+            recv_client_bank_code,
+            recv_client_account_num: AccountNum::new(recv_client_account_num),
+        }
+    }
+
+    /// 출금계좌 지정 방식에 따른 API endpoint
+    pub fn endpoint(&self) -> TransferEndpoint {
+        match self.fintech_use_num {
+            Some(_) => TransferEndpoint::WithdrawFinNum,
+            None => TransferEndpoint::WithdrawAcntNum,
+        }
+    }
+
+    pub fn bank_tran_id(&self) -> &str {
+        &self.bank_tran_id
+    }
+}
+
+/// 출금이체 API response body
+/// - api_tran_id: 거래고유번호(API)
+/// - api_tran_dtm: 거래일시(밀리세컨드)
+/// - rsp_code: 응답코드(API)(5자리)
+/// - rsp_message: 응답메시지(API)(300자리)
+/// - dps_bank_code_std: 입금기관.표준코드
+/// - dps_bank_code_sub: 입금기관.점별코드
+/// - dps_bank_name: 입금기관명
+/// - dps_account_num_masked: 마스킹된 입금계좌번호
+/// - dps_print_content: 입금계좌인자내역
+/// - dps_account_holder_name: 수취인성명
+/// - bank_tran_id: 거래고유번호(참가기관)
+/// - bank_tran_date: 거래일자(참가기관)
+/// - bank_code_tran: 응답코드를 부여한 참가기관.표준코드
+/// - bank_rsp_code: 응답코드(참가기관)(3자리)
+/// - bank_rsp_message: 응답메시지(참가기관)
+/// - fintech_use_num: 출금계좌 핀테크이용번호
+/// - account_alias: 출금계좌별명
+/// - bank_code_std: 출금기관.표준코드
+/// - bank_code_sub: 출금기관.점별코드
+/// - bank_name: 출금기관명
+/// - account_num_masked: 마스킹된 출금계좌번호
+/// - print_content: 출금계좌인자내역
+/// - tran_amt: 거래금액
+/// - account_holder_name: 송금인성명
+/// - wd_limit_remain_amt: 출금한도잔여금액
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseBody {
+    api_tran_id: String,
+    api_tran_dtm: ApiTranDtm,
+    rsp_code: ResponseCode,
+    rsp_message: String,
+    dps_bank_code_std: Option<String>,
+    dps_bank_code_sub: Option<String>,
+    dps_bank_name: Option<String>,
+    dps_account_num_masked: Option<String>,
+    dps_print_content: Option<String>,
+    dps_account_holder_name: Option<String>,
+    bank_tran_id: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    bank_tran_date: Option<Date>,
+    bank_code_tran: Option<String>,
+    bank_rsp_code: Option<BankResponseCode>,
+    bank_rsp_message: Option<String>,
+    fintech_use_num: Option<String>,
+    account_alias: Option<String>,
+    bank_code_std: Option<String>,
+    bank_code_sub: Option<String>,
+    bank_name: Option<String>,
+    account_num_masked: Option<String>,
+    print_content: Option<String>,
+    tran_amt: Option<String>,
+    account_holder_name: Option<String>,
+    wd_limit_remain_amt: Option<String>,
+}
+
+impl ResponseBody {
+    pub fn api_tran_id(&self) -> &str {
+        &self.api_tran_id
+    }
+
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+
+    pub fn bank_tran_id(&self) -> Option<&str> {
+        self.bank_tran_id.as_deref()
+    }
+
+    pub fn bank_rsp_code(&self) -> Option<&BankResponseCode> {
+        self.bank_rsp_code.as_ref()
+    }
+
+    pub fn bank_rsp_message(&self) -> Option<&str> {
+        self.bank_rsp_message.as_deref()
+    }
+
+    pub fn tran_amt(&self) -> Option<&str> {
+        self.tran_amt.as_deref()
+    }
+
+    pub fn wd_limit_remain_amt(&self) -> Option<&str> {
+        self.wd_limit_remain_amt.as_deref()
+    }
+
+    /// 처리결과를 알 수 없어 이체결과조회가 필요한지 여부
+    pub fn is_outcome_unknown(&self) -> bool {
+        is_outcome_unknown(&self.rsp_code, self.bank_rsp_code.as_ref())
+    }
+}
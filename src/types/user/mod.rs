@@ -0,0 +1,3 @@
+pub mod user_info;
+
+pub use super::response_code::ResponseCode;
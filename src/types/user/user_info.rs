@@ -0,0 +1,145 @@
+use super::ResponseCode;
+use crate::types::{
+    datetime::{empty_as_none, ApiTranDtm, Date, TranDtime},
+    HttpMethod,
+};
+use serde::{Deserialize, Serialize};
+
+pub const METHOD: HttpMethod = HttpMethod::Get;
+
+/// 사용자정보조회 API request query
+/// - user_seq_no: 사용자일련번호
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RequestBody {
+    user_seq_no: String,
+}
+
+impl RequestBody {
+    pub fn new(user_seq_no: String) -> Self {
+        Self { user_seq_no }
+    }
+}
+
+/// 사용자정보조회 API response body
+/// - api_tran_id: 거래고유번호(API)
+/// - api_tran_dtm: 거래일시(밀리세컨드)
+/// - rsp_code: 응답코드(API)(5자리)
+/// - rsp_message: 응답메시지(API)(300자리)
+/// - user_seq_no: 사용자일련번호
+/// - user_ci: 사용자 CI
+/// - user_name: 사용자명
+/// - res_cnt: 등록계좌개수
+/// - res_list: 등록계좌목록
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseBody {
+    api_tran_id: String,
+    api_tran_dtm: ApiTranDtm,
+    rsp_code: ResponseCode,
+    rsp_message: String,
+    user_seq_no: Option<String>,
+    user_ci: Option<String>,
+    user_name: Option<String>,
+    res_cnt: Option<String>,
+    #[serde(default)]
+    res_list: Vec<Account>,
+}
+
+impl ResponseBody {
+    pub fn api_tran_id(&self) -> &str {
+        &self.api_tran_id
+    }
+
+    pub fn rsp_code(&self) -> &ResponseCode {
+        &self.rsp_code
+    }
+
+    pub fn rsp_message(&self) -> &str {
+        &self.rsp_message
+    }
+
+    pub fn user_seq_no(&self) -> Option<&str> {
+        self.user_seq_no.as_deref()
+    }
+
+    pub fn user_name(&self) -> Option<&str> {
+        self.user_name.as_deref()
+    }
+
+    pub fn res_list(&self) -> &[Account] {
+        &self.res_list
+    }
+}
+
+/// 등록계좌
+/// - fintech_use_num: 핀테크이용번호
+/// - account_alias: 계좌별명
+/// - bank_code_std: 출금(개설)기관.표준코드
+/// - bank_code_sub: 출금(개설)기관.점별코드
+/// - bank_name: 출금(개설)기관명
+/// - savings_bank_name: 개별저축은행명
+/// - account_num_masked: 계좌번호(마스킹)
+/// - account_holder_name: 예금주성명
+/// - account_holder_type: 계좌구분("P": 개인)
+/// - inquiry_agree_yn: 조회서비스 동의여부
+/// - inquiry_agree_dtime: 조회서비스 동의일시
+/// - transfer_agree_yn: 출금서비스 동의여부
+/// - transfer_agree_dtime: 출금서비스 동의일시
+/// - payer_num: 납부자번호
+/// - account_state: 계좌상태("01": 사용, "09": 해지)
+/// - account_issue_date: 계좌개설일
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Account {
+    fintech_use_num: String,
+    account_alias: Option<String>,
+    bank_code_std: String,
+    bank_code_sub: Option<String>,
+    bank_name: Option<String>,
+    savings_bank_name: Option<String>,
+    account_num_masked: Option<String>,
+    account_holder_name: Option<String>,
+    account_holder_type: Option<String>,
+    inquiry_agree_yn: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    inquiry_agree_dtime: Option<TranDtime>,
+    transfer_agree_yn: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    transfer_agree_dtime: Option<TranDtime>,
+    payer_num: Option<String>,
+    account_state: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    account_issue_date: Option<Date>,
+}
+
+impl Account {
+    pub fn fintech_use_num(&self) -> &str {
+        &self.fintech_use_num
+    }
+
+    pub fn bank_code_std(&self) -> &str {
+        &self.bank_code_std
+    }
+
+    pub fn account_num_masked(&self) -> Option<&str> {
+        self.account_num_masked.as_deref()
+    }
+
+    pub fn account_holder_name(&self) -> Option<&str> {
+        self.account_holder_name.as_deref()
+    }
+
+    pub fn inquiry_agreed(&self) -> bool {
+        self.inquiry_agree_yn.as_deref() == Some("Y")
+    }
+
+    pub fn transfer_agreed(&self) -> bool {
+        self.transfer_agree_yn.as_deref() == Some("Y")
+    }
+
+    pub fn inquiry_agree_dtime(&self) -> Option<TranDtime> {
+        self.inquiry_agree_dtime
+    }
+
+    pub fn transfer_agree_dtime(&self) -> Option<TranDtime> {
+        self.transfer_agree_dtime
+    }
+}
@@ -0,0 +1,68 @@
+use open_banking_api::{
+    api::oauth::OAuthApi as AsyncOAuthApi,
+    blocking::OAuthApi,
+    mock::{MockAccount, MockKftc},
+    types::{oauth::token, user::user_info},
+};
+
+const CLIENT_ID: &str = "mock-client";
+const CLIENT_SECRET: &str = "mock-secret";
+const USER_SEQ_NO: &str = "1100000001";
+const USER_FIN: &str = "120220000000000000000001";
+
+fn setup() -> (MockKftc, OAuthApi) {
+    let mock = MockKftc::new();
+    mock.add_client(CLIENT_ID.to_string(), CLIENT_SECRET.to_string());
+    mock.add_user(
+        USER_SEQ_NO.to_string(),
+        "홍길동".to_string(),
+        "mock-ci".to_string(),
+    );
+    mock.add_account(
+        MockAccount::new(
+            USER_FIN.to_string(),
+            "097".to_string(),
+            "1000000001".to_string(),
+            "홍길동".to_string(),
+            100_000,
+        )
+        .owned_by(USER_SEQ_NO.to_string()),
+    );
+    let api = OAuthApi::build(AsyncOAuthApi::builder().transport(mock.transport())).unwrap();
+    (mock, api)
+}
+
+fn user_info(mock: &MockKftc, api: &OAuthApi) -> user_info::ResponseBody {
+    let access_token = api
+        .token(&token::RequestBody::new(
+            mock.authorization_code(USER_SEQ_NO),
+            CLIENT_ID.to_string(),
+            CLIENT_SECRET.to_string(),
+            "http://localhost/callback".to_string(),
+        ))
+        .unwrap()
+        .access_token()
+        .unwrap()
+        .to_string();
+    api.user_info(
+        access_token,
+        &user_info::RequestBody::new(USER_SEQ_NO.to_string()),
+    )
+    .unwrap()
+}
+
+#[test]
+fn blocking_client_calls_the_api() {
+    let (mock, api) = setup();
+    let response = user_info(&mock, &api);
+    assert_eq!(response.user_name(), Some("홍길동"));
+    assert_eq!(response.res_list()[0].fintech_use_num(), USER_FIN);
+}
+
+#[tokio::test]
+async fn blocking_client_can_be_used_inside_a_tokio_runtime() {
+    let (mock, api) = setup();
+    let response = user_info(&mock, &api);
+    assert_eq!(response.user_name(), Some("홍길동"));
+    drop(api);
+}
@@ -0,0 +1,124 @@
+use open_banking_api::{
+    api::{cassette::Cassette, oauth::OAuthApi},
+    mock::{MockAccount, MockKftc},
+    types::{datetime::TranDtime, inquiry::balance, oauth::token, user::user_info},
+    ApiError,
+};
+use std::path::PathBuf;
+
+const CLIENT_ID: &str = "mock-client";
+const CLIENT_SECRET: &str = "mock-secret";
+const USER_SEQ_NO: &str = "1100000001";
+const USER_CI: &str = "mock-user-ci";
+const USER_FIN: &str = "120220000000000000000001";
+const ACCOUNT_NUM: &str = "1000000001";
+
+fn cassette_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "open-banking-api-{}-{}.json",
+        name,
+        std::process::id()
+    ))
+}
+
+fn mock() -> MockKftc {
+    let mock = MockKftc::new();
+    mock.add_client(CLIENT_ID.to_string(), CLIENT_SECRET.to_string());
+    mock.add_user(
+        USER_SEQ_NO.to_string(),
+        "홍길동".to_string(),
+        USER_CI.to_string(),
+    );
+    mock.add_account(
+        MockAccount::new(
+            USER_FIN.to_string(),
+            "097".to_string(),
+            ACCOUNT_NUM.to_string(),
+            "홍길동".to_string(),
+            50_000,
+        )
+        .owned_by(USER_SEQ_NO.to_string()),
+    );
+    mock
+}
+
+/// 토큰발급 -> 사용자정보조회 -> 잔액조회
+async fn flow(api: &OAuthApi, code: String) -> Result<(String, String), ApiError> {
+    let token = api
+        .token(&token::RequestBody::new(
+            code,
+            CLIENT_ID.to_string(),
+            CLIENT_SECRET.to_string(),
+            "http://localhost/callback".to_string(),
+        ))
+        .await?;
+    let access_token = token.access_token().unwrap_or_default().to_string();
+    let user = api
+        .user_info(
+            access_token.clone(),
+            &user_info::RequestBody::new(USER_SEQ_NO.to_string()),
+        )
+        .await?;
+    let balance = api
+        .balance(
+            access_token,
+            &balance::RequestBody::new(
+                "M202300001U000000001".to_string(),
+                USER_FIN.to_string(),
+                TranDtime::now(),
+            ),
+        )
+        .await?;
+    Ok((
+        user.user_name().unwrap_or_default().to_string(),
+        balance.balance_amt().unwrap_or_default().to_string(),
+    ))
+}
+
+#[tokio::test]
+async fn recorded_interactions_replay_without_secrets() {
+    let path = cassette_path("replay");
+    let mock = mock();
+    let recorder = Cassette::record(&path, mock.transport());
+    let api = OAuthApi::new().with_transport(recorder.transport());
+    let recorded = flow(&api, mock.authorization_code(USER_SEQ_NO))
+        .await
+        .unwrap();
+    assert_eq!(recorded, ("홍길동".to_string(), "50000".to_string()));
+    assert_eq!(recorder.interactions().len(), 3);
+
+    let file = std::fs::read_to_string(&path).unwrap();
+    for secret in [CLIENT_SECRET, USER_CI, ACCOUNT_NUM, "mock-access-token"] {
+        assert!(!file.contains(secret), "{} was recorded", secret);
+    }
+
+    let player = Cassette::replay(&path).unwrap();
+    let api = OAuthApi::new().with_transport(player.transport());
+    let replayed = flow(&api, "unused".to_string()).await.unwrap();
+    assert_eq!(replayed, recorded);
+
+    let error = flow(&api, "unused".to_string()).await.unwrap_err();
+    assert!(matches!(error, ApiError::Cassette { .. }));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn replay_rejects_requests_in_a_different_order() {
+    let path = cassette_path("order");
+    let mock = mock();
+    let api = OAuthApi::new().with_transport(Cassette::record(&path, mock.transport()).transport());
+    flow(&api, mock.authorization_code(USER_SEQ_NO))
+        .await
+        .unwrap();
+
+    let api = OAuthApi::new().with_transport(Cassette::replay(&path).unwrap().transport());
+    let error = api
+        .user_info(
+            "unused".to_string(),
+            &user_info::RequestBody::new(USER_SEQ_NO.to_string()),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ApiError::Cassette { .. }));
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,94 @@
+use open_banking_api::{
+    api::oauth::OAuthApi,
+    config::ClientConfig,
+    mock::{MockAccount, MockKftc},
+    types::{
+        endpoint::{Endpoint, Environment, OAuthEndpoint},
+        user::user_info,
+    },
+    ApiError,
+};
+
+const CONFIG: &str = r#"
+client_id = "mock-client"
+client_secret = "mock-secret"
+institution_code = "M202300001"
+redirect_uri = "http://localhost/callback"
+environment = "prod"
+"#;
+const USER_SEQ_NO: &str = "1100000001";
+
+#[test]
+fn config_is_loaded_from_toml_and_env() {
+    let config = ClientConfig::from_toml_str(CONFIG).unwrap();
+    assert_eq!(config.client_id(), "mock-client");
+    assert_eq!(config.client_secret().expose(), "mock-secret");
+    assert_eq!(config.environment(), Environment::Prod);
+    assert!(!format!("{:?}", config).contains("mock-secret"));
+    assert!(ClientConfig::from_toml_str("client_id = \"mock-client\"").is_err());
+
+    let first = config.bank_tran_id_generator().next().unwrap();
+    let second = config.clone().bank_tran_id_generator().next().unwrap();
+    assert!(first.starts_with("M202300001U"));
+    assert_ne!(first, second);
+
+    std::env::set_var("OPEN_BANKING_CLIENT_ID", "env-client");
+    std::env::set_var("OPEN_BANKING_CLIENT_SECRET", "env-secret");
+    std::env::set_var("OPEN_BANKING_INSTITUTION_CODE", "M202300001");
+    std::env::set_var("OPEN_BANKING_REDIRECT_URI", "http://localhost/callback");
+    std::env::set_var("OPEN_BANKING_ENVIRONMENT", "staging");
+    assert!(matches!(ClientConfig::from_env(), Err(ApiError::Config(_))));
+    std::env::set_var("OPEN_BANKING_ENVIRONMENT", "test");
+    let config = ClientConfig::from_env().unwrap();
+    assert_eq!(config.client_id(), "env-client");
+    assert_eq!(config.environment(), Environment::Test);
+}
+
+#[tokio::test]
+async fn configured_client_fills_credentials_and_selects_environment() {
+    let mock = MockKftc::new();
+    mock.add_client("mock-client".to_string(), "mock-secret".to_string());
+    mock.add_user(
+        USER_SEQ_NO.to_string(),
+        "홍길동".to_string(),
+        "mock-ci".to_string(),
+    );
+    mock.add_account(
+        MockAccount::new(
+            "120220000000000000000001".to_string(),
+            "097".to_string(),
+            "1000000001".to_string(),
+            "홍길동".to_string(),
+            0,
+        )
+        .owned_by(USER_SEQ_NO.to_string()),
+    );
+    let config = ClientConfig::from_toml_str(CONFIG).unwrap();
+    let api = OAuthApi::builder()
+        .config(config)
+        .transport(mock.transport())
+        .build()
+        .unwrap();
+    assert_eq!(api.environment(), Environment::Prod);
+    assert_eq!(
+        Endpoint::OAuth(OAuthEndpoint::Token).url(api.environment()),
+        "https://openapi.openbanking.or.kr/oauth/2.0/token"
+    );
+
+    let token = api
+        .issue_token(mock.authorization_code(USER_SEQ_NO))
+        .await
+        .unwrap();
+    let access_token = token.access_token().unwrap().to_string();
+    api.user_info(
+        access_token.clone(),
+        &user_info::RequestBody::new(USER_SEQ_NO.to_string()),
+    )
+    .await
+    .unwrap();
+    api.revoke_token(access_token).await.unwrap();
+    assert_eq!(api.issue_oob_token().await.unwrap().scope(), Some("oob"));
+
+    let error = OAuthApi::new().issue_oob_token().await.unwrap_err();
+    assert!(matches!(error, ApiError::Config(_)));
+}
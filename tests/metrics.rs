@@ -0,0 +1,164 @@
+use open_banking_api::{
+    api::{
+        metrics::Metrics,
+        oauth::OAuthApi,
+        rate_limit::{RateLimit, RateLimiter, ThrottleMode},
+        retry::RetryPolicy,
+    },
+    mock::{Fault, MockAccount, MockKftc},
+    types::{
+        datetime::TranDtime,
+        endpoint::{Endpoint, EndpointGroup, UserEndpoint},
+        inquiry::balance,
+        oauth::token,
+        response_code::ResponseCode,
+        transfer::withdraw,
+        user::user_info,
+        CntrAccountType, TransferPurpose,
+    },
+};
+use std::{sync::Arc, time::Duration};
+
+const USER_SEQ_NO: &str = "1100000001";
+const USER_FIN: &str = "120220000000000000000001";
+const CNTR_ACCOUNT_NUM: &str = "9990001234";
+
+#[tokio::test]
+async fn calls_errors_throttles_tokens_and_transfer_amounts_are_exported() {
+    let mock = MockKftc::new();
+    mock.add_client("mock-client".to_string(), "mock-secret".to_string());
+    mock.add_user(
+        USER_SEQ_NO.to_string(),
+        "홍길동".to_string(),
+        "mock-ci".to_string(),
+    );
+    mock.add_account(
+        MockAccount::new(
+            USER_FIN.to_string(),
+            "097".to_string(),
+            "1000000001".to_string(),
+            "홍길동".to_string(),
+            100_000,
+        )
+        .owned_by(USER_SEQ_NO.to_string()),
+    );
+    mock.add_account(MockAccount::new(
+        "120220000000000000000099".to_string(),
+        "097".to_string(),
+        CNTR_ACCOUNT_NUM.to_string(),
+        "이용기관".to_string(),
+        0,
+    ));
+    let metrics = Metrics::new();
+    let rate_limiter = RateLimiter::new()
+        .with_limit(
+            EndpointGroup::Inquiry,
+            RateLimit {
+                capacity: 1,
+                refill_per_sec: 0.0,
+                mode: ThrottleMode::Reject,
+            },
+        )
+        .with_limit(
+            EndpointGroup::User,
+            RateLimit {
+                capacity: 1,
+                refill_per_sec: 100.0,
+                mode: ThrottleMode::Queue,
+            },
+        );
+    let api = OAuthApi::builder()
+        .transport(mock.transport())
+        .metrics(metrics.clone())
+        .rate_limiter(Arc::new(rate_limiter))
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        })
+        .build()
+        .unwrap();
+
+    let access_token = api
+        .token(&token::RequestBody::new(
+            mock.authorization_code(USER_SEQ_NO),
+            "mock-client".to_string(),
+            "mock-secret".to_string(),
+            "http://localhost/callback".to_string(),
+        ))
+        .await
+        .unwrap()
+        .access_token()
+        .unwrap()
+        .to_string();
+    let body = withdraw::RequestBody::new_fin_num(
+        "M202300001U000000001".to_string(),
+        CntrAccountType::Account,
+        CNTR_ACCOUNT_NUM.to_string(),
+        "상품대금".to_string(),
+        USER_FIN.to_string(),
+        "30000".to_string(),
+        TranDtime::now(),
+        "홍길동".to_string(),
+        USER_FIN.to_string(),
+        USER_SEQ_NO.to_string(),
+        TransferPurpose::Remittance,
+        "이용기관".to_string(),
+        "097".to_string(),
+        CNTR_ACCOUNT_NUM.to_string(),
+    );
+    api.withdraw(access_token.clone(), &body).await.unwrap();
+    api.withdraw(access_token.clone(), &body).await.unwrap_err();
+    for bank_tran_id in ["M202300001U000000002", "M202300001U000000003"] {
+        let _ = api
+            .balance(
+                access_token.clone(),
+                &balance::RequestBody::new(
+                    bank_tran_id.to_string(),
+                    USER_FIN.to_string(),
+                    TranDtime::now(),
+                ),
+            )
+            .await;
+    }
+
+    mock.inject_on(
+        Endpoint::User(UserEndpoint::UserInfo),
+        Fault::Reject(ResponseCode::TooMuchCall),
+    );
+    api.user_info(
+        access_token.clone(),
+        &user_info::RequestBody::new(USER_SEQ_NO.to_string()),
+    )
+    .await
+    .unwrap();
+
+    let rendered = metrics.render();
+    for line in [
+        "# TYPE open_banking_calls_total counter",
+        r#"open_banking_calls_total{endpoint="oauth.token",rsp_code=""} 1"#,
+        r#"open_banking_calls_total{endpoint="transfer.withdraw_fin_num",rsp_code="A0000"} 1"#,
+        r#"open_banking_calls_total{endpoint="inquiry.balance",rsp_code="A0000"} 1"#,
+        r#"open_banking_calls_total{endpoint="user.user_info",rsp_code="A0000"} 1"#,
+        r#"open_banking_errors_total{endpoint="inquiry.balance",code="RATE_LIMITED"} 1"#,
+        r#"open_banking_attempts_total{endpoint="user.user_info",code="O0013"} 1"#,
+        r#"open_banking_attempts_total{endpoint="user.user_info",code="OK"} 1"#,
+        r#"open_banking_attempts_total{endpoint="inquiry.balance",code="OK"} 1"#,
+        r#"open_banking_throttled_total{group="inquiry",outcome="rejected"} 1"#,
+        r#"open_banking_throttled_total{group="user",outcome="queued"} 1"#,
+        r#"open_banking_throttle_wait_seconds_count{group="user"} 1"#,
+        r#"open_banking_tokens_issued_total{grant_type="authorization_code"} 1"#,
+        r#"open_banking_transfer_amount_won_bucket{endpoint="transfer.withdraw_fin_num",le="100000"} 1"#,
+        r#"open_banking_transfer_amount_won_sum{endpoint="transfer.withdraw_fin_num"} 30000"#,
+        r#"open_banking_call_duration_seconds_count{endpoint="inquiry.balance"} 2"#,
+    ] {
+        assert!(
+            rendered.lines().any(|l| l == line),
+            "{} not in\n{}",
+            line,
+            rendered
+        );
+    }
+    assert!(rendered.lines().any(
+        |l| l.starts_with(r#"open_banking_errors_total{endpoint="transfer.withdraw_fin_num""#)
+    ));
+}
@@ -0,0 +1,538 @@
+use open_banking_api::{
+    api::{oauth::OAuthApi, retry::RetryPolicy, transfer::TransferResolver},
+    mock::{Fault, MockAccount, MockKftc},
+    types::{
+        datetime::{Date, TranDtime},
+        endpoint::{Endpoint, InquiryEndpoint, TransferEndpoint, UserEndpoint},
+        inquiry::{
+            balance, receive, remit_list,
+            transaction_list::{self, InquiryType},
+        },
+        oauth::{revoke, token},
+        response_code::{BankResponseCode, ResponseCode},
+        transfer::{deposit, result, withdraw},
+        user::user_info,
+        CntrAccountType, SortOrder, TransferPurpose,
+    },
+    ApiError,
+};
+use std::time::Duration;
+
+const CLIENT_ID: &str = "mock-client";
+const CLIENT_SECRET: &str = "mock-secret";
+const USER_SEQ_NO: &str = "1100000001";
+const USER_FIN: &str = "120220000000000000000001";
+const RECIPIENT_FIN: &str = "120220000000000000000002";
+const CNTR_FIN: &str = "120220000000000000000099";
+const CNTR_ACCOUNT_NUM: &str = "9990001234";
+const RESTRICTED_FIN: &str = "120220000000000000000003";
+
+fn setup() -> (MockKftc, OAuthApi) {
+    let mock = MockKftc::new();
+    mock.add_client(CLIENT_ID.to_string(), CLIENT_SECRET.to_string());
+    mock.add_user(
+        USER_SEQ_NO.to_string(),
+        "홍길동".to_string(),
+        "mock-ci".to_string(),
+    );
+    mock.add_account(
+        MockAccount::new(
+            USER_FIN.to_string(),
+            "097".to_string(),
+            "1000000001".to_string(),
+            "홍길동".to_string(),
+            100_000,
+        )
+        .owned_by(USER_SEQ_NO.to_string()),
+    );
+    mock.add_account(MockAccount::new(
+        RECIPIENT_FIN.to_string(),
+        "088".to_string(),
+        "2000000002".to_string(),
+        "김철수".to_string(),
+        0,
+    ));
+    mock.add_account(MockAccount::new(
+        CNTR_FIN.to_string(),
+        "097".to_string(),
+        CNTR_ACCOUNT_NUM.to_string(),
+        "이용기관".to_string(),
+        1_000_000,
+    ));
+    let api = OAuthApi::builder()
+        .transport(mock.transport())
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        })
+        .build()
+        .unwrap();
+    (mock, api)
+}
+
+async fn user_token(mock: &MockKftc, api: &OAuthApi) -> String {
+    let body = token::RequestBody::new(
+        mock.authorization_code(USER_SEQ_NO),
+        CLIENT_ID.to_string(),
+        CLIENT_SECRET.to_string(),
+        "http://localhost/callback".to_string(),
+    );
+    let response = api.token(&body).await.unwrap();
+    assert_eq!(response.user_seq_no(), Some(USER_SEQ_NO));
+    response.access_token().unwrap().to_string()
+}
+
+async fn oob_token(api: &OAuthApi) -> String {
+    let body = token::RequestBody::new_client_credentials(
+        CLIENT_ID.to_string(),
+        CLIENT_SECRET.to_string(),
+    );
+    let response = api.token(&body).await.unwrap();
+    assert_eq!(response.scope(), Some("oob"));
+    response.access_token().unwrap().to_string()
+}
+
+fn withdraw_body(bank_tran_id: &str, tran_amt: i64) -> withdraw::RequestBody {
+    withdraw_from(USER_FIN, bank_tran_id, tran_amt)
+}
+
+fn withdraw_from(
+    fintech_use_num: &str,
+    bank_tran_id: &str,
+    tran_amt: i64,
+) -> withdraw::RequestBody {
+    withdraw::RequestBody::new_fin_num(
+        bank_tran_id.to_string(),
+        CntrAccountType::Account,
+        CNTR_ACCOUNT_NUM.to_string(),
+        "상품대금".to_string(),
+        fintech_use_num.to_string(),
+        tran_amt.to_string(),
+        TranDtime::now(),
+        "홍길동".to_string(),
+        fintech_use_num.to_string(),
+        USER_SEQ_NO.to_string(),
+        TransferPurpose::Remittance,
+        "이용기관".to_string(),
+        "097".to_string(),
+        CNTR_ACCOUNT_NUM.to_string(),
+    )
+}
+
+fn rsp_code(error: &ApiError) -> Option<&ResponseCode> {
+    match error {
+        ApiError::OpenBanking { rsp_code, .. } => Some(rsp_code),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn user_token_lists_accounts_until_revoked() {
+    let (mock, api) = setup();
+    let access_token = user_token(&mock, &api).await;
+    let query = user_info::RequestBody::new(USER_SEQ_NO.to_string());
+
+    let response = api.user_info(access_token.clone(), &query).await.unwrap();
+    assert!(response.api_tran_id().starts_with("MOCK"));
+    assert_eq!(response.rsp_code(), &ResponseCode::ApiSuccess);
+    assert_eq!(response.user_name(), Some("홍길동"));
+    assert_eq!(response.res_list().len(), 1);
+    assert_eq!(response.res_list()[0].fintech_use_num(), USER_FIN);
+    assert!(response.res_list()[0].inquiry_agree_dtime().is_some());
+
+    api.revoke(&revoke::RequestBody::new(
+        CLIENT_ID.to_string(),
+        CLIENT_SECRET.to_string(),
+        access_token.clone(),
+    ))
+    .await
+    .unwrap();
+    let error = api.user_info(access_token, &query).await.unwrap_err();
+    assert_eq!(rsp_code(&error), Some(&ResponseCode::AccessTokenDenied));
+}
+
+#[tokio::test]
+async fn withdraw_debits_balance_and_appears_in_remit_list() {
+    let (mock, api) = setup();
+    let access_token = user_token(&mock, &api).await;
+
+    let response = api
+        .withdraw(
+            access_token.clone(),
+            &withdraw_body("M202300001U000000001", 30_000),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.tran_amt(), Some("30000"));
+    assert!(response.api_tran_id().starts_with("MOCK"));
+    assert_eq!(response.rsp_code(), &ResponseCode::ApiSuccess);
+    assert_eq!(response.bank_rsp_code(), Some(&BankResponseCode::Success));
+    assert_eq!(mock.balance(USER_FIN), Some(70_000));
+    assert_eq!(mock.balance(CNTR_FIN), Some(1_030_000));
+
+    let query = balance::RequestBody::new(
+        "M202300001U000000002".to_string(),
+        USER_FIN.to_string(),
+        TranDtime::now(),
+    );
+    let response = api.balance(access_token.clone(), &query).await.unwrap();
+    assert_eq!(response.balance_amt(), Some("70000"));
+    assert!(response.api_tran_id().starts_with("MOCK"));
+    assert_eq!(response.bank_rsp_code(), Some(&BankResponseCode::Success));
+
+    let error = api
+        .withdraw(
+            access_token,
+            &withdraw_body("M202300001U000000003", 100_000),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(rsp_code(&error), Some(&ResponseCode::BankError));
+    assert_eq!(mock.balance(USER_FIN), Some(70_000));
+
+    let today = Date::today();
+    let body = remit_list::RequestBody::new(
+        "M202300001U000000004".to_string(),
+        CntrAccountType::Account,
+        CNTR_ACCOUNT_NUM.to_string(),
+        today,
+        None,
+        today,
+        None,
+        SortOrder::Ascending,
+        TranDtime::now(),
+    );
+    let response = api.remit_list(oob_token(&api).await, &body).await.unwrap();
+    assert_eq!(response.res_list().len(), 1);
+    assert_eq!(response.res_list()[0].tran_amt(), "30000");
+    assert_eq!(response.res_list()[0].remitter_name(), Some("홍길동"));
+}
+
+#[tokio::test]
+async fn deposit_to_verified_recipient_is_confirmed_by_transfer_result() {
+    let (mock, api) = setup();
+    let access_token = oob_token(&api).await;
+
+    let recipient = api
+        .verify_recipient(
+            access_token.clone(),
+            receive::RequestBody::new(
+                "M202300001U000000011".to_string(),
+                CntrAccountType::Account,
+                CNTR_ACCOUNT_NUM.to_string(),
+                "088".to_string(),
+                "2000000002".to_string(),
+                "환불".to_string(),
+                "5000".to_string(),
+                "이용기관".to_string(),
+                "097".to_string(),
+                CNTR_ACCOUNT_NUM.to_string(),
+                "CLIENT01".to_string(),
+                TransferPurpose::Remittance,
+                TranDtime::now(),
+            ),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(recipient.account_holder_name(), "김철수");
+
+    let item = deposit::RequestItem::new_acnt_num(
+        "M202300001U000000012".to_string(),
+        &recipient,
+        "5000".to_string(),
+        "이용기관".to_string(),
+        "097".to_string(),
+        CNTR_ACCOUNT_NUM.to_string(),
+        "CLIENT01".to_string(),
+        TransferPurpose::Remittance,
+    );
+    let batches = deposit::RequestBody::batches(
+        CntrAccountType::Account,
+        CNTR_ACCOUNT_NUM.to_string(),
+        "NONE".to_string(),
+        "환불".to_string(),
+        true,
+        TranDtime::now(),
+        vec![item],
+        deposit::MAX_REQ_CNT,
+    );
+    let outcome = api.deposit_batch(access_token.clone(), batches).await;
+    assert_eq!(outcome.succeeded.len(), 1);
+    assert!(outcome.failed.is_empty() && outcome.rejected.is_empty());
+    assert!(outcome.pending.is_empty() && outcome.unreported.is_empty());
+    assert_eq!(mock.balance(RECIPIENT_FIN), Some(5_000));
+    assert_eq!(mock.balance(CNTR_FIN), Some(995_000));
+
+    let body = result::RequestBody::new(
+        result::CheckType::Deposit,
+        TranDtime::now(),
+        vec![result::RequestItem::new(
+            "M202300001U000000012".to_string(),
+            Date::today(),
+            "5000".to_string(),
+        )],
+    );
+    let response = api.transfer_result(access_token, &body).await.unwrap();
+    assert!(response.res_list()[0].is_success());
+}
+
+#[tokio::test]
+async fn partially_failed_deposit_batch_keeps_per_item_results() {
+    let (mock, api) = setup();
+    let access_token = oob_token(&api).await;
+    let item = |bank_tran_id: &str, fintech_use_num: &str| {
+        deposit::RequestItem::new_fin_num(
+            bank_tran_id.to_string(),
+            fintech_use_num.to_string(),
+            "환불".to_string(),
+            "5000".to_string(),
+            "이용기관".to_string(),
+            "097".to_string(),
+            CNTR_ACCOUNT_NUM.to_string(),
+            "CLIENT01".to_string(),
+            TransferPurpose::Remittance,
+        )
+    };
+    let batches = deposit::RequestBody::batches(
+        CntrAccountType::Account,
+        CNTR_ACCOUNT_NUM.to_string(),
+        "NONE".to_string(),
+        "환불".to_string(),
+        false,
+        TranDtime::now(),
+        vec![
+            item("M202300001U000000061", RECIPIENT_FIN),
+            item("M202300001U000000062", "120220000000000000000404"),
+        ],
+        deposit::MAX_REQ_CNT,
+    );
+    let outcome = api.deposit_batch(access_token, batches).await;
+    assert!(outcome.rejected.is_empty() && outcome.unresolved.is_empty());
+    assert!(outcome.unreported.is_empty());
+    assert_eq!(outcome.succeeded.len(), 1);
+    assert_eq!(outcome.succeeded[0].bank_tran_id(), "M202300001U000000061");
+    // 목록에 없는 참가기관 응답코드는 실패로 단정하지 않고 이체결과조회 대상으로 분류
+    assert!(outcome.failed.is_empty());
+    assert_eq!(outcome.pending.len(), 1);
+    assert_eq!(outcome.pending[0].bank_tran_id(), "M202300001U000000062");
+    assert_eq!(mock.balance(RECIPIENT_FIN), Some(5_000));
+    assert_eq!(mock.balance(CNTR_FIN), Some(995_000));
+}
+
+#[tokio::test]
+async fn expired_token_is_reported_with_its_response_code() {
+    let (mock, api) = setup();
+    let access_token = user_token(&mock, &api).await;
+    let query = user_info::RequestBody::new(USER_SEQ_NO.to_string());
+
+    mock.inject_on(Endpoint::User(UserEndpoint::UserInfo), Fault::ExpiredToken);
+    let error = api
+        .user_info(access_token.clone(), &query)
+        .await
+        .unwrap_err();
+    assert_eq!(rsp_code(&error), Some(&ResponseCode::AccessTokenExpired));
+    assert!(!error.is_retryable());
+
+    api.user_info(access_token.clone(), &query).await.unwrap();
+    mock.expire_token(&access_token);
+    let error = api.user_info(access_token, &query).await.unwrap_err();
+    assert_eq!(rsp_code(&error), Some(&ResponseCode::AccessTokenExpired));
+}
+
+#[tokio::test]
+async fn bank_timeout_on_withdraw_is_not_retried_and_resolves_by_result_inquiry() {
+    let (mock, api) = setup();
+    let access_token = user_token(&mock, &api).await;
+    let endpoint = Endpoint::Transfer(TransferEndpoint::WithdrawFinNum);
+
+    mock.inject_on(endpoint, Fault::BankTimeout);
+    let error = api
+        .withdraw(access_token, &withdraw_body("M202300001U000000021", 10_000))
+        .await
+        .unwrap_err();
+    assert_eq!(rsp_code(&error), Some(&ResponseCode::BankTimeout));
+    assert!(error.is_outcome_unknown());
+    assert_eq!(mock.calls(endpoint), 1);
+
+    let resolver = TransferResolver {
+        initial_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+        max_attempts: 3,
+    };
+    let item = api
+        .resolve_transfer(
+            oob_token(&api).await,
+            result::CheckType::Withdraw,
+            result::RequestItem::new(
+                "M202300001U000000021".to_string(),
+                Date::today(),
+                "10000".to_string(),
+            ),
+            &resolver,
+        )
+        .await
+        .unwrap();
+    assert!(item.is_success());
+    assert_eq!(mock.balance(USER_FIN), Some(90_000));
+}
+
+#[tokio::test]
+async fn timeouts_and_throttling_are_retried_for_user_info() {
+    let (mock, api) = setup();
+    let access_token = user_token(&mock, &api).await;
+    let endpoint = Endpoint::User(UserEndpoint::UserInfo);
+    let query = user_info::RequestBody::new(USER_SEQ_NO.to_string());
+
+    mock.inject_on(endpoint, Fault::Timeout);
+    mock.inject_on(endpoint, Fault::Reject(ResponseCode::TooMuchCall));
+    let response = api.user_info(access_token, &query).await.unwrap();
+    assert_eq!(response.user_name(), Some("홍길동"));
+    assert_eq!(mock.calls(endpoint), 3);
+}
+
+#[tokio::test]
+async fn inquiries_carrying_bank_tran_id_are_not_retried() {
+    let (mock, api) = setup();
+    let access_token = user_token(&mock, &api).await;
+    let endpoint = Endpoint::Inquiry(InquiryEndpoint::Balance);
+    let query = balance::RequestBody::new(
+        "M202300001U000000031".to_string(),
+        USER_FIN.to_string(),
+        TranDtime::now(),
+    );
+
+    mock.inject_on(endpoint, Fault::Reject(ResponseCode::TooMuchCall));
+    let error = api.balance(access_token, &query).await.unwrap_err();
+    assert_eq!(rsp_code(&error), Some(&ResponseCode::TooMuchCall));
+    assert_eq!(mock.calls(endpoint), 1);
+}
+
+#[tokio::test]
+async fn transfers_update_balance_and_transaction_list() {
+    let (mock, api) = setup();
+    let access_token = user_token(&mock, &api).await;
+    for (bank_tran_id, tran_amt) in [
+        ("M202300001U000000041", 10_000),
+        ("M202300001U000000042", 20_000),
+    ] {
+        api.withdraw(access_token.clone(), &withdraw_body(bank_tran_id, tran_amt))
+            .await
+            .unwrap();
+    }
+
+    let query = balance::RequestBody::new(
+        "M202300001U000000043".to_string(),
+        USER_FIN.to_string(),
+        TranDtime::now(),
+    );
+    let response = api.balance(access_token.clone(), &query).await.unwrap();
+    assert_eq!(response.balance_amt(), Some("70000"));
+
+    let today = Date::today();
+    let query = transaction_list::RequestBody::new(
+        "M202300001U000000044".to_string(),
+        USER_FIN.to_string(),
+        InquiryType::Withdraw,
+        today,
+        today,
+        SortOrder::Descending,
+        TranDtime::now(),
+    );
+    let response = api
+        .transaction_list(access_token.clone(), &query)
+        .await
+        .unwrap();
+    assert_eq!(response.balance_amt(), Some("70000"));
+    assert!(!response.has_next_page());
+    let amounts: Vec<(&str, &str)> = response
+        .res_list()
+        .iter()
+        .map(|transaction| (transaction.tran_amt(), transaction.after_balance_amt()))
+        .collect();
+    assert_eq!(amounts, [("20000", "70000"), ("10000", "90000")]);
+    assert!(response
+        .res_list()
+        .iter()
+        .all(|transaction| !transaction.is_deposit()));
+
+    let query = transaction_list::RequestBody::new(
+        "M202300001U000000045".to_string(),
+        USER_FIN.to_string(),
+        InquiryType::Deposit,
+        today,
+        today,
+        SortOrder::Ascending,
+        TranDtime::now(),
+    );
+    let response = api.transaction_list(access_token, &query).await.unwrap();
+    assert!(response.res_list().is_empty());
+}
+
+fn restricted_account() -> MockAccount {
+    MockAccount::new(
+        RESTRICTED_FIN.to_string(),
+        "097".to_string(),
+        "1000000003".to_string(),
+        "홍길동".to_string(),
+        100_000,
+    )
+    .owned_by(USER_SEQ_NO.to_string())
+}
+
+#[tokio::test]
+async fn agreements_from_account_registration_are_enforced() {
+    let (mock, api) = setup();
+    mock.add_account(restricted_account().with_agreements(false, true));
+    let access_token = user_token(&mock, &api).await;
+    let response = api
+        .user_info(
+            access_token.clone(),
+            &user_info::RequestBody::new(USER_SEQ_NO.to_string()),
+        )
+        .await
+        .unwrap();
+    let account = &response.res_list()[1];
+    assert!(!account.inquiry_agreed() && account.transfer_agreed());
+
+    let query = balance::RequestBody::new(
+        "M202300001U000000051".to_string(),
+        RESTRICTED_FIN.to_string(),
+        TranDtime::now(),
+    );
+    let error = api.balance(access_token.clone(), &query).await.unwrap_err();
+    assert_eq!(rsp_code(&error), Some(&ResponseCode::AccessDenied));
+
+    mock.set_agreements(USER_FIN, true, false);
+    let error = api
+        .withdraw(access_token, &withdraw_body("M202300001U000000052", 10_000))
+        .await
+        .unwrap_err();
+    assert_eq!(rsp_code(&error), Some(&ResponseCode::AccessDenied));
+    assert_eq!(mock.balance(USER_FIN), Some(100_000));
+}
+
+#[tokio::test]
+async fn daily_withdrawal_limit_is_enforced() {
+    let (mock, api) = setup();
+    mock.add_account(restricted_account().with_daily_withdraw_limit(50_000));
+    let access_token = user_token(&mock, &api).await;
+
+    let response = api
+        .withdraw(
+            access_token.clone(),
+            &withdraw_from(RESTRICTED_FIN, "M202300001U000000061", 30_000),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.tran_amt(), Some("30000"));
+    let error = api
+        .withdraw(
+            access_token,
+            &withdraw_from(RESTRICTED_FIN, "M202300001U000000062", 30_000),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(rsp_code(&error), Some(&ResponseCode::BankError));
+    assert_eq!(mock.balance(RESTRICTED_FIN), Some(70_000));
+}
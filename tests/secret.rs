@@ -0,0 +1,122 @@
+use open_banking_api::{
+    api::pipeline::{ApiRequest, RawResponse},
+    types::{
+        datetime::TranDtime,
+        endpoint::{Endpoint, OAuthEndpoint},
+        inquiry::real_name,
+        oauth::{authorize, revoke, token},
+        secret::mask_account_num,
+        transfer::deposit,
+        AccountHolderInfoType, AuthorizationHeader, CntrAccountType, TransferPurpose,
+    },
+};
+use reqwest::{header::HeaderMap, StatusCode};
+
+#[test]
+fn debug_output_hides_credentials_and_user_ci() {
+    let debug = format!(
+        "{:?} {:?} {:?} {:?} {:?}",
+        token::RequestBody::new(
+            "authorization-code".to_string(),
+            "client-id".to_string(),
+            "client-secret".to_string(),
+            "http://localhost/callback".to_string(),
+        ),
+        revoke::RequestBody::new(
+            "client-id".to_string(),
+            "client-secret".to_string(),
+            "access-token".to_string(),
+        ),
+        authorize::Header::new(
+            "1100000001".to_string(),
+            "user-ci".to_string(),
+            "access-token".to_string(),
+        ),
+        real_name::RequestBody::new(
+            "M202300001U000000001".to_string(),
+            "097".to_string(),
+            "1234567890".to_string(),
+            AccountHolderInfoType::BirthDate,
+            "880101".to_string(),
+            TranDtime::now(),
+        ),
+        deposit::RequestBody::new(
+            CntrAccountType::Account,
+            "9990001234".to_string(),
+            "wd-pass-phrase".to_string(),
+            "print".to_string(),
+            true,
+            TranDtime::now(),
+            vec![deposit::RequestItem::new_fin_num(
+                "M202300001U000000002".to_string(),
+                "120220000000000000000001".to_string(),
+                "print".to_string(),
+                "1000".to_string(),
+                "홍길동".to_string(),
+                "097".to_string(),
+                "1000000001".to_string(),
+                "1100000001".to_string(),
+                TransferPurpose::Remittance,
+            )],
+        )
+        .unwrap(),
+    );
+    assert!(debug.contains("client-id") && debug.contains("1100000001"));
+    for secret in [
+        "authorization-code",
+        "client-secret",
+        "access-token",
+        "user-ci",
+        "880101",
+        "wd-pass-phrase",
+    ] {
+        assert!(!debug.contains(secret), "{} was printed", secret);
+    }
+}
+
+#[test]
+fn account_numbers_are_masked_like_account_num_masked() {
+    assert_eq!(mask_account_num("1234567890"), "1234567***");
+    assert_eq!(mask_account_num("12"), "***");
+}
+
+#[test]
+fn requests_and_error_bodies_hide_secrets() {
+    let request = ApiRequest::new(
+        Endpoint::OAuth(OAuthEndpoint::Token),
+        token::METHOD,
+        Some(&AuthorizationHeader::new("access-token".to_string())),
+        Some(&token::RequestBody::new(
+            "authorization-code".to_string(),
+            "client-id".to_string(),
+            "client-secret".to_string(),
+            "http://localhost/callback".to_string(),
+        )),
+    )
+    .unwrap();
+    assert!(request.headers["authorization"].is_sensitive());
+    let debug = format!("{:?}", request);
+    assert!(debug.contains("client-id"));
+    for secret in ["access-token", "authorization-code", "client-secret"] {
+        assert!(!debug.contains(secret), "{} was printed", secret);
+    }
+
+    let body = format!(
+        r#"{{"rsp_code":"A0001","account_num":"1234567890","user_ci":"user-ci","print_content":"{}"}}"#,
+        "x".repeat(10_000)
+    );
+    let response = RawResponse {
+        status: StatusCode::INTERNAL_SERVER_ERROR,
+        headers: HeaderMap::new(),
+        body,
+    };
+    let debug = format!("{:?}", response);
+    let error = response.check().unwrap_err();
+    for output in [debug, error.to_string(), format!("{:?}", error)] {
+        assert!(output.contains("[REDACTED]") && output.contains("(truncated)"));
+        assert!(output.len() < 6_000);
+        for secret in ["1234567890", "user-ci"] {
+            assert!(!output.contains(secret), "{} was printed", secret);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use open_banking_api::{
+    api::{
+        oauth::OAuthApi,
+        rate_limit::{RateLimit, RateLimiter, ThrottleMode},
+    },
+    config::ClientConfig,
+    mock::{Fault, MockAccount, MockKftc},
+    tenant::{Tenant, TenantRegistry},
+    types::{
+        datetime::TranDtime,
+        endpoint::{Endpoint, EndpointGroup, Environment, OAuthEndpoint},
+        inquiry::real_name,
+        AccountHolderInfoType,
+    },
+    ApiError,
+};
+use std::{sync::Arc, time::Duration};
+
+fn config(client_id: &str, institution_code: &str) -> ClientConfig {
+    ClientConfig::new(
+        client_id.to_string(),
+        format!("{}-secret", client_id),
+        institution_code.to_string(),
+        "http://localhost/callback".to_string(),
+        Environment::Test,
+    )
+}
+
+fn real_name_body(tenant: &Tenant) -> real_name::RequestBody {
+    real_name::RequestBody::new(
+        tenant.next_bank_tran_id().unwrap(),
+        "097".to_string(),
+        "1000000001".to_string(),
+        AccountHolderInfoType::BirthDate,
+        "880101".to_string(),
+        TranDtime::now(),
+    )
+}
+
+fn setup() -> (MockKftc, TenantRegistry) {
+    let mock = MockKftc::new();
+    for client_id in ["shop", "pay"] {
+        mock.add_client(client_id.to_string(), format!("{}-secret", client_id));
+    }
+    mock.add_account(MockAccount::new(
+        "120220000000000000000001".to_string(),
+        "097".to_string(),
+        "1000000001".to_string(),
+        "홍길동".to_string(),
+        0,
+    ));
+    let registry = TenantRegistry::new();
+    // shop은 조회 API를 1회만 호출할 수 있도록 제한
+    let throttled = RateLimiter::new().with_limit(
+        EndpointGroup::Inquiry,
+        RateLimit {
+            capacity: 1,
+            refill_per_sec: 0.0,
+            mode: ThrottleMode::Reject,
+        },
+    );
+    let shop = Tenant::build(
+        config("shop", "M202300001"),
+        OAuthApi::builder()
+            .transport(mock.transport())
+            .rate_limiter(Arc::new(throttled)),
+    )
+    .unwrap();
+    registry.register("shop".to_string(), shop);
+    let pay = Tenant::build(
+        config("pay", "M202300002"),
+        OAuthApi::builder().transport(mock.transport()),
+    )
+    .unwrap();
+    registry.register("pay".to_string(), pay);
+    (mock, registry)
+}
+
+#[tokio::test]
+async fn tenants_have_their_own_tokens_and_bank_tran_ids() {
+    let (mock, registry) = setup();
+    let shop = registry.get("shop").unwrap();
+    let pay = registry.get("pay").unwrap();
+
+    let shop_token = shop.oob_token().await.unwrap();
+    assert_eq!(shop.oob_token().await.unwrap(), shop_token);
+    let pay_token = pay.oob_token().await.unwrap();
+    assert_ne!(pay_token, shop_token);
+    assert_eq!(mock.calls(Endpoint::OAuth(OAuthEndpoint::Token)), 2);
+
+    assert!(shop.next_bank_tran_id().unwrap().starts_with("M202300001U"));
+    assert!(pay.next_bank_tran_id().unwrap().starts_with("M202300002U"));
+    assert!(matches!(
+        registry.get("unknown").err(),
+        Some(ApiError::UnknownTenant { .. })
+    ));
+}
+
+#[tokio::test]
+async fn one_tenants_throttling_does_not_block_another() {
+    let (_mock, registry) = setup();
+    let shop = registry.get("shop").unwrap();
+    let pay = registry.get("pay").unwrap();
+    let shop_token = shop.oob_token().await.unwrap();
+    let pay_token = pay.oob_token().await.unwrap();
+
+    shop.api()
+        .real_name(shop_token.clone(), &real_name_body(&shop))
+        .await
+        .unwrap();
+    let error = shop
+        .api()
+        .real_name(shop_token, &real_name_body(&shop))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ApiError::RateLimited { .. }));
+
+    for _ in 0..3 {
+        pay.api()
+            .real_name(pay_token.clone(), &real_name_body(&pay))
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn concurrent_callers_share_one_oob_token_request() {
+    let (mock, registry) = setup();
+    let shop = registry.get("shop").unwrap();
+    mock.inject_on(
+        Endpoint::OAuth(OAuthEndpoint::Token),
+        Fault::Delay(Duration::from_millis(50)),
+    );
+
+    let (first, second, third) = tokio::join!(shop.oob_token(), shop.oob_token(), shop.oob_token());
+    let token = first.unwrap();
+    assert_eq!(second.unwrap(), token);
+    assert_eq!(third.unwrap(), token);
+    assert_eq!(mock.calls(Endpoint::OAuth(OAuthEndpoint::Token)), 1);
+}
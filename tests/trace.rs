@@ -0,0 +1,182 @@
+use open_banking_api::{
+    api::{oauth::OAuthApi, retry::RetryPolicy},
+    mock::{Fault, MockAccount, MockKftc},
+    types::{
+        datetime::TranDtime,
+        endpoint::{Endpoint, InquiryEndpoint, UserEndpoint},
+        inquiry::balance,
+        oauth::token,
+        response_code::ResponseCode,
+        user::user_info,
+    },
+};
+use std::{
+    io::Write,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tracing_subscriber::fmt::{format::FmtSpan, MakeWriter};
+
+const USER_SEQ_NO: &str = "1100000001";
+const USER_FIN: &str = "120220000000000000000001";
+
+/// 출력된 log를 모아 두는 writer
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Captured {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[tokio::test]
+async fn calls_are_traced_with_transaction_ids_and_without_secrets() {
+    let captured = Captured::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(captured.clone())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let mock = MockKftc::new();
+    mock.add_client("mock-client".to_string(), "mock-secret".to_string());
+    mock.add_user(
+        USER_SEQ_NO.to_string(),
+        "홍길동".to_string(),
+        "mock-ci".to_string(),
+    );
+    mock.add_account(
+        MockAccount::new(
+            USER_FIN.to_string(),
+            "097".to_string(),
+            "1000000001".to_string(),
+            "홍길동".to_string(),
+            10_000,
+        )
+        .owned_by(USER_SEQ_NO.to_string()),
+    );
+    let api = OAuthApi::builder()
+        .transport(mock.transport())
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        })
+        .build()
+        .unwrap();
+    let access_token = api
+        .token(&token::RequestBody::new(
+            mock.authorization_code(USER_SEQ_NO),
+            "mock-client".to_string(),
+            "mock-secret".to_string(),
+            "http://localhost/callback".to_string(),
+        ))
+        .await
+        .unwrap()
+        .access_token()
+        .unwrap()
+        .to_string();
+
+    mock.inject_on(
+        Endpoint::User(UserEndpoint::UserInfo),
+        Fault::Reject(ResponseCode::TooMuchCall),
+    );
+    api.user_info(
+        access_token.clone(),
+        &user_info::RequestBody::new(USER_SEQ_NO.to_string()),
+    )
+    .await
+    .unwrap();
+    api.balance(
+        access_token.clone(),
+        &balance::RequestBody::new(
+            "M202300001U000000001".to_string(),
+            USER_FIN.to_string(),
+            TranDtime::now(),
+        ),
+    )
+    .await
+    .unwrap();
+    mock.inject_on(
+        Endpoint::Inquiry(InquiryEndpoint::Balance),
+        Fault::Reject(ResponseCode::TooMuchCall),
+    );
+    api.balance(
+        access_token.clone(),
+        &balance::RequestBody::new(
+            "M202300001U000000002".to_string(),
+            USER_FIN.to_string(),
+            TranDtime::now(),
+        ),
+    )
+    .await
+    .unwrap_err();
+
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    let failed_span = output
+        .lines()
+        .find(|line| line.contains("M202300001U000000002") && line.contains("close"))
+        .unwrap();
+    for field in ["rsp_code=\"O0013\"", "retry_count=0"] {
+        assert!(
+            failed_span.contains(field),
+            "{} not in {}",
+            field,
+            failed_span
+        );
+    }
+    let user_info_span = output
+        .lines()
+        .find(|line| line.contains("UserInfo") && line.contains("close"))
+        .unwrap();
+    for field in ["rsp_code=\"A0000\"", "status=200", "retry_count=1"] {
+        assert!(
+            user_info_span.contains(field),
+            "{} not in {}",
+            field,
+            user_info_span
+        );
+    }
+    let balance_span = output
+        .lines()
+        .find(|line| line.contains("Balance") && line.contains("close"))
+        .unwrap();
+    for field in [
+        "bank_tran_id=\"M202300001U000000001\"",
+        "rsp_code=\"A0000\"",
+        "bank_rsp_code=\"000\"",
+        "status=200",
+        "retry_count=0",
+        "api_tran_id=",
+        "latency_ms=",
+    ] {
+        assert!(
+            balance_span.contains(field),
+            "{} not in {}",
+            field,
+            balance_span
+        );
+    }
+    for secret in [
+        access_token.as_str(),
+        "mock-secret",
+        "mock-ci",
+        "1000000001",
+    ] {
+        assert!(!output.contains(secret), "{} was traced", secret);
+    }
+}